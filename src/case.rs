@@ -0,0 +1,70 @@
+/// Case conversion rules for the `rename_all` container attribute
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenameRule {
+    LowerCase,
+    UpperCase,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    pub const NAMES: &'static [&'static str] = &[
+        "lowercase",
+        "UPPERCASE",
+        "PascalCase",
+        "camelCase",
+        "snake_case",
+        "SCREAMING_SNAKE_CASE",
+        "kebab-case",
+        "SCREAMING-KEBAB-CASE",
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "lowercase" => RenameRule::LowerCase,
+            "UPPERCASE" => RenameRule::UpperCase,
+            "PascalCase" => RenameRule::PascalCase,
+            "camelCase" => RenameRule::CamelCase,
+            "snake_case" => RenameRule::SnakeCase,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnakeCase,
+            "kebab-case" => RenameRule::KebabCase,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebabCase,
+            _ => return None,
+        })
+    }
+
+    /// Apply the rule to a variant name, variant names are expected to be PascalCase
+    pub fn apply(self, variant: &str) -> String {
+        match self {
+            RenameRule::LowerCase => variant.to_ascii_lowercase(),
+            RenameRule::UpperCase => variant.to_ascii_uppercase(),
+            RenameRule::PascalCase => variant.to_string(),
+            RenameRule::CamelCase => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            RenameRule::SnakeCase => separate_words(variant, '_'),
+            RenameRule::ScreamingSnakeCase => separate_words(variant, '_').to_ascii_uppercase(),
+            RenameRule::KebabCase => separate_words(variant, '-'),
+            RenameRule::ScreamingKebabCase => separate_words(variant, '-').to_ascii_uppercase(),
+        }
+    }
+}
+
+fn separate_words(variant: &str, separator: char) -> String {
+    let mut result = String::with_capacity(variant.len() + 4);
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            result.push(separator);
+        }
+        result.push(ch.to_ascii_lowercase());
+    }
+    result
+}
@@ -0,0 +1,229 @@
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::{Ident, Visibility};
+
+/// Name of the generated error type for an enum
+pub fn parse_error_name(enum_name: &Ident) -> Ident {
+    Ident::new(&format!("{}ParseError", enum_name), enum_name.span())
+}
+
+/// Generate the error type returned when parsing a strict enum fails
+///
+/// The unmatched input is only stored in the error when `String` is available
+pub fn parse_error(
+    enum_name: &Ident,
+    vis: &Visibility,
+    expected: &[&String],
+    string_type: Option<&TokenStream>,
+) -> TokenStream {
+    let span = enum_name.span();
+    let error_name = parse_error_name(enum_name);
+    let doc = format!(
+        "Error returned when parsing an unknown value into [`{}`]",
+        enum_name
+    );
+    let expected_list = expected
+        .iter()
+        .map(|value| format!("\"{}\"", value.escape_default()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let expected = expected.iter();
+
+    let std_error = if cfg!(feature = "std") {
+        quote_spanned! { span =>
+            impl ::std::error::Error for #error_name {}
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    match string_type {
+        Some(string_type) => quote_spanned! { span =>
+            #[doc = #doc]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+            #vis struct #error_name {
+                value: #string_type,
+            }
+
+            impl #error_name {
+                fn new(value: #string_type) -> Self {
+                    #error_name { value }
+                }
+
+                /// The input that didn't match any of the known values
+                pub fn value(&self) -> &str {
+                    &self.value
+                }
+
+                /// Take the input that didn't match any of the known values
+                pub fn into_value(self) -> #string_type {
+                    self.value
+                }
+
+                /// All values that are accepted when parsing
+                pub fn expected(&self) -> &'static [&'static str] {
+                    &[#(#expected),*]
+                }
+            }
+
+            impl ::core::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::write!(f, "unknown value \"{}\", expected one of {}", self.value, #expected_list)
+                }
+            }
+
+            #std_error
+        },
+        None => quote_spanned! { span =>
+            #[doc = #doc]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+            #vis struct #error_name {
+                _private: (),
+            }
+
+            impl #error_name {
+                fn new() -> Self {
+                    #error_name { _private: () }
+                }
+
+                /// All values that are accepted when parsing
+                pub fn expected(&self) -> &'static [&'static str] {
+                    &[#(#expected),*]
+                }
+            }
+
+            impl ::core::fmt::Display for #error_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::write!(f, "unknown value, expected one of {}", #expected_list)
+                }
+            }
+
+            #std_error
+        },
+    }
+}
+
+/// Name of the generated error type for abbreviation parsing
+pub fn abbrev_error_name(enum_name: &Ident) -> Ident {
+    Ident::new(&format!("{}AbbrevError", enum_name), enum_name.span())
+}
+
+/// Generate the error type returned by `from_abbrev`, distinguishing unknown from ambiguous input
+pub fn abbrev_error(enum_name: &Ident, vis: &Visibility, expected: &[&String]) -> TokenStream {
+    let span = enum_name.span();
+    let error_name = abbrev_error_name(enum_name);
+    let doc = format!(
+        "Error returned when parsing an abbreviation into [`{}`] fails",
+        enum_name
+    );
+    let expected_list = expected
+        .iter()
+        .map(|value| format!("\"{}\"", value.escape_default()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let std_error = if cfg!(feature = "std") {
+        quote_spanned! { span =>
+            impl ::std::error::Error for #error_name {}
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote_spanned! { span =>
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #vis enum #error_name {
+            /// The input isn't a prefix of any known value
+            Unknown,
+            /// The input is a prefix of the values of multiple variants
+            Ambiguous,
+        }
+
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match self {
+                    #error_name::Unknown => ::core::write!(f, "unknown value, expected one of {}", #expected_list),
+                    #error_name::Ambiguous => f.write_str("ambiguous abbreviation, matches multiple values"),
+                }
+            }
+        }
+
+        #std_error
+    }
+}
+
+/// Name of the generated error type for reading the enum from an environment variable
+pub fn env_error_name(enum_name: &Ident) -> Ident {
+    Ident::new(&format!("{}EnvError", enum_name), enum_name.span())
+}
+
+/// Generate the error type returned by `from_env`, holding the name of the variable
+pub fn env_error(enum_name: &Ident, vis: &Visibility, expected: &[&String]) -> TokenStream {
+    let span = enum_name.span();
+    let error_name = env_error_name(enum_name);
+    let doc = format!(
+        "Error returned when reading [`{}`] from an environment variable fails",
+        enum_name
+    );
+    let expected_list = expected
+        .iter()
+        .map(|value| format!("\"{}\"", value.escape_default()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    quote_spanned! { span =>
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #vis enum #error_name {
+            /// The variable isn't set
+            Missing {
+                /// The name of the variable
+                variable: ::std::string::String,
+            },
+            /// The value of the variable isn't valid unicode
+            NotUnicode {
+                /// The name of the variable
+                variable: ::std::string::String,
+            },
+            /// The value of the variable isn't one of the known values
+            Invalid {
+                /// The name of the variable
+                variable: ::std::string::String,
+                /// The value of the variable
+                value: ::std::string::String,
+            },
+        }
+
+        impl #error_name {
+            /// The name of the variable
+            pub fn variable(&self) -> &str {
+                match self {
+                    #error_name::Missing { variable }
+                    | #error_name::NotUnicode { variable }
+                    | #error_name::Invalid { variable, .. } => variable,
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match self {
+                    #error_name::Missing { variable } => {
+                        ::core::write!(f, "environment variable \"{}\" is not set", variable)
+                    }
+                    #error_name::NotUnicode { variable } => {
+                        ::core::write!(f, "environment variable \"{}\" is not valid unicode", variable)
+                    }
+                    #error_name::Invalid { variable, value } => ::core::write!(
+                        f,
+                        "invalid value \"{}\" for environment variable \"{}\", expected one of {}",
+                        value, variable, #expected_list
+                    ),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #error_name {}
+    }
+}
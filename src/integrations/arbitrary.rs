@@ -0,0 +1,55 @@
+use super::with_params;
+use crate::{Enum, FallbackType};
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::Lifetime;
+
+/// `Arbitrary` picking one of the known variants, or occasionally an arbitrary string for enums
+/// with a fallback option
+pub fn derive(input: &Enum) -> TokenStream {
+    let arbitrary = input.crate_path("arbitrary");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // borrowed fallback options borrow from the unstructured data
+    let arbitrary_generics;
+    let (arbitrary_impl_generics, lifetime, fallback) = match input.fallback_type {
+        Some(FallbackType::Cow(lifetime)) | Some(FallbackType::Str(lifetime)) => (
+            impl_generics,
+            lifetime.clone(),
+            Some(quote_spanned! { span =>
+                <Self as ::core::convert::From<&#lifetime str>>::from(value)
+            }),
+        ),
+        fallback_type => {
+            arbitrary_generics = with_params(input.generics, &["'__arbitrary"], &[]);
+            let fallback = fallback_type.and_then(|_| input.parse_str(TokenStream::new()));
+            (
+                arbitrary_generics.split_for_impl().0,
+                Lifetime::new("'__arbitrary", Span::call_site()),
+                fallback,
+            )
+        }
+    };
+
+    let fallback = match fallback {
+        Some(parse) => quote_spanned! { span =>
+            if Self::COUNT == 0 || u.ratio(1u8, 4u8)? {
+                let value = <&#lifetime str as #arbitrary::Arbitrary<#lifetime>>::arbitrary(u)?;
+                return ::core::result::Result::Ok(#parse);
+            }
+        },
+        None => TokenStream::new(),
+    };
+
+    quote_spanned! { span =>
+        impl #arbitrary_impl_generics #arbitrary::Arbitrary<#lifetime> for #enum_name #ty_generics #where_clause {
+            fn arbitrary(u: &mut #arbitrary::Unstructured<#lifetime>) -> #arbitrary::Result<Self> {
+                #fallback
+                let index = u.choose_index(Self::COUNT)?;
+                ::core::result::Result::Ok(Self::iter().nth(index).unwrap())
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use super::is_graphql_enum;
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// A GraphQL enum for strict enums, a string scalar that parses known values into their variant
+/// otherwise
+///
+/// GraphQL enum values have to be valid names while the string values can be anything, strict
+/// enums with other values are exposed as a scalar too and reject unknown values when parsing.
+pub fn derive(input: &Enum) -> TokenStream {
+    let async_graphql = input.crate_path("async_graphql");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let type_name = enum_name.to_string();
+
+    // scalars need to be 'static
+    if !input.generics.params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let parse = input.parse_str(quote_spanned! { span =>
+        #async_graphql::InputValueError::custom(::std::format!(
+            "invalid value \"{}\" for {}",
+            value,
+            #type_name
+        ))
+    });
+
+    if is_graphql_enum(input) {
+        return graphql_enum(input, parse);
+    }
+
+    quote_spanned! { span =>
+        #[#async_graphql::Scalar(name = #type_name)]
+        impl #async_graphql::ScalarType for #enum_name {
+            fn parse(value: #async_graphql::Value) -> #async_graphql::InputValueResult<Self> {
+                match value {
+                    #async_graphql::Value::String(value) => {
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                    value => ::core::result::Result::Err(#async_graphql::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn is_valid(value: &#async_graphql::Value) -> bool {
+                match value {
+                    #async_graphql::Value::String(_) => true,
+                    _ => false,
+                }
+            }
+
+            fn to_value(&self) -> #async_graphql::Value {
+                #async_graphql::Value::String(::std::string::String::from(self.as_str()))
+            }
+        }
+    }
+}
+
+/// `InputType` and `OutputType` for a GraphQL enum with the string values as enum values
+///
+/// Since these are string values, string literals are accepted as input too
+fn graphql_enum(input: &Enum, parse: Option<TokenStream>) -> TokenStream {
+    let async_graphql = input.crate_path("async_graphql");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let type_name = enum_name.to_string();
+
+    let enum_values = input.options.iter().map(|option| {
+        let name = &option.name;
+        let description = match &option.description {
+            Some(description) => quote_spanned! { option.span =>
+                ::core::option::Option::Some(::std::string::String::from(#description))
+            },
+            None => quote_spanned! { option.span => ::core::option::Option::None },
+        };
+        let deprecation = if option.deprecated {
+            quote_spanned! { option.span =>
+                #async_graphql::registry::Deprecation::Deprecated { reason: ::core::option::Option::None }
+            }
+        } else {
+            quote_spanned! { option.span => #async_graphql::registry::Deprecation::NoDeprecated }
+        };
+        quote_spanned! { option.span =>
+            enum_values.insert(
+                ::std::string::String::from(#name),
+                #async_graphql::registry::MetaEnumValue {
+                    name: ::std::string::String::from(#name),
+                    description: #description,
+                    deprecation: #deprecation,
+                    visible: ::core::option::Option::None,
+                    inaccessible: false,
+                    tags: ::std::vec::Vec::new(),
+                    directive_invocations: ::std::vec::Vec::new(),
+                },
+            );
+        }
+    });
+
+    quote_spanned! { span =>
+        impl #async_graphql::InputType for #enum_name {
+            type RawValueType = Self;
+
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#type_name)
+            }
+
+            fn create_type_info(registry: &mut #async_graphql::registry::Registry) -> ::std::string::String {
+                registry.create_input_type::<Self, _>(#async_graphql::registry::MetaTypeId::Enum, |_| {
+                    let mut enum_values = #async_graphql::indexmap::IndexMap::new();
+                    #(#enum_values)*
+                    #async_graphql::registry::MetaType::Enum {
+                        name: ::std::string::String::from(#type_name),
+                        description: ::core::option::Option::None,
+                        enum_values,
+                        visible: ::core::option::Option::None,
+                        inaccessible: false,
+                        tags: ::std::vec::Vec::new(),
+                        rust_typename: ::core::option::Option::Some(::core::any::type_name::<Self>()),
+                        directive_invocations: ::std::vec::Vec::new(),
+                        requires_scopes: ::std::vec::Vec::new(),
+                    }
+                })
+            }
+
+            fn parse(value: ::core::option::Option<#async_graphql::Value>) -> #async_graphql::InputValueResult<Self> {
+                match value.unwrap_or_default() {
+                    #async_graphql::Value::Enum(value) => {
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                    #async_graphql::Value::String(value) => {
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                    value => ::core::result::Result::Err(#async_graphql::InputValueError::expected_type(value)),
+                }
+            }
+
+            fn to_value(&self) -> #async_graphql::Value {
+                #async_graphql::Value::Enum(#async_graphql::Name::new(self.as_str()))
+            }
+
+            fn as_raw_value(&self) -> ::core::option::Option<&Self::RawValueType> {
+                ::core::option::Option::Some(self)
+            }
+        }
+
+        impl #async_graphql::OutputType for #enum_name {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#type_name)
+            }
+
+            fn create_type_info(registry: &mut #async_graphql::registry::Registry) -> ::std::string::String {
+                <Self as #async_graphql::InputType>::create_type_info(registry)
+            }
+
+            async fn resolve(
+                &self,
+                _: &#async_graphql::ContextSelectionSet<'_>,
+                _: &#async_graphql::Positioned<#async_graphql::parser::types::Field>,
+            ) -> #async_graphql::ServerResult<#async_graphql::Value> {
+                ::core::result::Result::Ok(<Self as #async_graphql::InputType>::to_value(self))
+            }
+        }
+    }
+}
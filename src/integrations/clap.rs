@@ -0,0 +1,139 @@
+use crate::{string_path, Enum};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Ident, Visibility};
+
+/// `ValueEnum` listing the known values
+///
+/// Since clap only accepts the listed values for a `ValueEnum`, enums with a fallback option also
+/// get a value parser that accepts any value while still listing the known values in the help.
+pub fn derive(input: &Enum) -> TokenStream {
+    let clap = input.crate_path("clap");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let possible_value_arms = input.options.iter().map(|option| {
+        let span = option.span;
+        let ident = &option.ident;
+        let name = &option.name;
+        let aliases = if option.aliases.is_empty() {
+            TokenStream::new()
+        } else {
+            let aliases = option.aliases.iter();
+            quote_spanned! { span => .aliases([#(#aliases),*]) }
+        };
+        if option.catch_all {
+            let binding = option.with_field(enum_name, quote! { _ });
+            quote_spanned! { span =>
+                #binding => ::core::option::Option::None
+            }
+        } else {
+            quote_spanned! { span =>
+                #enum_name::#ident => ::core::option::Option::Some(#clap::builder::PossibleValue::new(#name)#aliases)
+            }
+        }
+    });
+    let skipped_arms = input.skipped.iter().map(|option| {
+        let span = option.span;
+        let ident = &option.ident;
+        quote_spanned! { span =>
+            #enum_name::#ident { .. } => ::core::option::Option::None
+        }
+    });
+
+    let owned_fallback = input
+        .fallback_type
+        .map_or(false, |fallback_type| fallback_type.is_owned());
+    let string_type = string_path();
+
+    let from_str = if owned_fallback {
+        quote_spanned! { span =>
+            fn from_str(input: &str, ignore_case: bool) -> ::core::result::Result<Self, #string_type> {
+                if ignore_case {
+                    if let ::core::option::Option::Some(value) = Self::iter().find(|value| value.as_str().eq_ignore_ascii_case(input)) {
+                        return ::core::result::Result::Ok(value);
+                    }
+                }
+                ::core::result::Result::Ok(Self::from(#string_type::from(input)))
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // the parser needs to be `'static`, so it's only provided for enums without generics
+    let value_parser = if owned_fallback && input.generics.params.is_empty() {
+        value_parser(enum_name, input.vis, &clap)
+    } else {
+        TokenStream::new()
+    };
+
+    quote_spanned! { span =>
+        impl #impl_generics #clap::ValueEnum for #enum_name #ty_generics #where_clause {
+            fn value_variants<'__a>() -> &'__a [Self] {
+                Self::VARIANTS
+            }
+
+            fn to_possible_value(&self) -> ::core::option::Option<#clap::builder::PossibleValue> {
+                match self {
+                    #(#possible_value_arms ,)*
+                    #(#skipped_arms ,)*
+                }
+            }
+
+            #from_str
+        }
+
+        #value_parser
+    }
+}
+
+fn value_parser(enum_name: &Ident, vis: &Visibility, clap: &TokenStream) -> TokenStream {
+    let parser_name = Ident::new(&format!("{}ValueParser", enum_name), enum_name.span());
+    let doc = format!(
+        "Value parser for [`{}`] that accepts any value, listing the known values as possible values",
+        enum_name
+    );
+
+    quote_spanned! { enum_name.span() =>
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::default::Default)]
+        #vis struct #parser_name;
+
+        impl #clap::builder::TypedValueParser for #parser_name {
+            type Value = #enum_name;
+
+            fn parse_ref(
+                &self,
+                cmd: &#clap::Command,
+                arg: ::core::option::Option<&#clap::Arg>,
+                value: &::std::ffi::OsStr,
+            ) -> ::core::result::Result<Self::Value, #clap::Error> {
+                let value = #clap::builder::TypedValueParser::parse_ref(
+                    &#clap::builder::StringValueParser::new(),
+                    cmd,
+                    arg,
+                    value,
+                )?;
+                ::core::result::Result::Ok(#enum_name::from(value))
+            }
+
+            fn possible_values(
+                &self,
+            ) -> ::core::option::Option<::std::boxed::Box<dyn ::core::iter::Iterator<Item = #clap::builder::PossibleValue> + '_>> {
+                ::core::option::Option::Some(::std::boxed::Box::new(
+                    #enum_name::iter().filter_map(|value| #clap::ValueEnum::to_possible_value(&value)),
+                ))
+            }
+        }
+
+        impl #clap::builder::ValueParserFactory for #enum_name {
+            type Parser = #parser_name;
+
+            fn value_parser() -> Self::Parser {
+                #parser_name
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// Helpers for building a `Select` prompt from the known variants
+///
+/// The items are listed in the order of `VARIANTS`, so the selected index is the ordinal of the
+/// variant.
+pub fn derive(input: &Enum) -> TokenStream {
+    let dialoguer = input.crate_path("dialoguer");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names = input.known_names();
+    let names = names.iter();
+
+    quote_spanned! { span =>
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// The string values of the known variants, to be used as items of a `Select` prompt
+            pub fn select_items() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            /// The variant for the index selected from `select_items`
+            pub fn from_selection(index: usize) -> ::core::option::Option<Self> {
+                Self::from_ordinal(index)
+            }
+
+            /// Let the user select one of the known variants in an interactive prompt
+            pub fn select(prompt: &str) -> #dialoguer::Result<Self> {
+                let index = #dialoguer::Select::new()
+                    .with_prompt(prompt)
+                    .items(Self::select_items())
+                    .default(0)
+                    .interact()?;
+                ::core::result::Result::Ok(Self::from_ordinal(index).expect("selected index out of range"))
+            }
+        }
+    }
+}
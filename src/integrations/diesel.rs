@@ -0,0 +1,65 @@
+use super::{where_predicates, with_params};
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `ToSql<Text, _>` and `FromSql<Text, _>` for any backend
+pub fn derive(input: &Enum) -> TokenStream {
+    let diesel = input.crate_path("diesel");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let generics = with_params(input.generics, &[], &["__DB"]);
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let where_predicates = where_predicates(input.generics);
+
+    let to_sql = quote_spanned! { span =>
+        impl #impl_generics #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB> for #enum_name #ty_generics
+        where
+            #where_predicates
+            __DB: #diesel::backend::Backend,
+            str: #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB>,
+        {
+            fn to_sql<'__b>(
+                &'__b self,
+                out: &mut #diesel::serialize::Output<'__b, '_, __DB>,
+            ) -> #diesel::serialize::Result {
+                <str as #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB>>::to_sql(self.as_str(), out)
+            }
+        }
+    };
+
+    let type_name = enum_name.to_string();
+    let from_sql = input
+        .parse_str(quote_spanned! { span =>
+            ::std::format!("invalid value \"{}\" for {}", value, #type_name).into()
+        })
+        .map(|parse| {
+            quote_spanned! { span =>
+                impl #impl_generics #diesel::deserialize::FromSql<#diesel::sql_types::Text, __DB> for #enum_name #ty_generics
+                where
+                    #where_predicates
+                    __DB: #diesel::backend::Backend,
+                    ::std::string::String: #diesel::deserialize::FromSql<#diesel::sql_types::Text, __DB>,
+                {
+                    fn from_sql(
+                        bytes: <__DB as #diesel::backend::Backend>::RawValue<'_>,
+                    ) -> #diesel::deserialize::Result<Self> {
+                        let value = <::std::string::String as #diesel::deserialize::FromSql<
+                            #diesel::sql_types::Text,
+                            __DB,
+                        >>::from_sql(bytes)?;
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                }
+            }
+        });
+
+    quote_spanned! { span =>
+        #to_sql
+
+        #from_sql
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{Enum, FallbackType};
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::Error;
+
+/// Conversions between the enum and `HeaderValue`
+///
+/// Known values are converted with `HeaderValue::from_static` without allocating. Since a
+/// catch-all value isn't necessarily a valid header value, enums with a catch-all variant only
+/// implement `TryFrom` into `HeaderValue`. Header values are decoded as lossy UTF-8 before parsing.
+pub fn derive(input: &Enum) -> TokenStream {
+    let http = input.crate_path("http");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fallible = input.accepts_unknown();
+
+    let mut known_arms = Vec::new();
+    for option in input.options.iter().filter(|option| !option.catch_all) {
+        // `HeaderValue::from_static` panics on anything but visible ASCII and tabs
+        if !option
+            .name
+            .bytes()
+            .all(|b| (32..127).contains(&b) || b == b'\t')
+        {
+            return Error::new(
+                option.span,
+                format!("\"{}\" is not a valid header value", option.name),
+            )
+            .to_compile_error();
+        }
+        let ident = &option.ident;
+        let name = &option.name;
+        known_arms.push(if fallible {
+            quote_spanned! { option.span =>
+                #enum_name::#ident => ::core::result::Result::Ok(#http::HeaderValue::from_static(#name))
+            }
+        } else {
+            quote_spanned! { option.span =>
+                #enum_name::#ident => #http::HeaderValue::from_static(#name)
+            }
+        });
+    }
+    let skipped_arms = input.skipped.iter().map(|option| {
+        let ident = &option.ident;
+        let message = format!("{}::{} has no string value", enum_name, ident);
+        quote_spanned! { option.span =>
+            #enum_name::#ident { .. } => ::core::panic!(#message)
+        }
+    });
+
+    let into_header = if fallible {
+        let other_arms = input
+            .options
+            .iter()
+            .filter(|option| option.catch_all)
+            .map(|option| {
+                let ident = &option.ident;
+                let value = if option.value_format().is_some() {
+                    quote_spanned! { option.span => &::std::string::ToString::to_string(&from) }
+                } else {
+                    quote_spanned! { option.span => from.as_str() }
+                };
+                quote_spanned! { option.span =>
+                    #enum_name::#ident { .. } => #http::HeaderValue::from_str(#value)
+                }
+            });
+        quote_spanned! { span =>
+            impl #impl_generics ::core::convert::TryFrom<#enum_name #ty_generics> for #http::HeaderValue #where_clause {
+                type Error = #http::header::InvalidHeaderValue;
+
+                fn try_from(from: #enum_name #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                    match from {
+                        #(#known_arms ,)*
+                        #(#other_arms ,)*
+                        #(#skipped_arms ,)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote_spanned! { span =>
+            impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #http::HeaderValue #where_clause {
+                fn from(from: #enum_name #ty_generics) -> Self {
+                    match from {
+                        #(#known_arms ,)*
+                        #(#skipped_arms ,)*
+                    }
+                }
+            }
+        }
+    };
+
+    let from_header = match input.fallback_type {
+        Some(FallbackType::Str(_)) => TokenStream::new(),
+        Some(_) => quote_spanned! { span =>
+            impl #impl_generics ::core::convert::From<&#http::HeaderValue> for #enum_name #ty_generics #where_clause {
+                fn from(value: &#http::HeaderValue) -> Self {
+                    <Self as ::core::convert::From<::std::string::String>>::from(
+                        ::std::string::String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                }
+            }
+        },
+        None => quote_spanned! { span =>
+            impl #impl_generics ::core::convert::TryFrom<&#http::HeaderValue> for #enum_name #ty_generics #where_clause {
+                type Error = <Self as ::core::str::FromStr>::Err;
+
+                fn try_from(value: &#http::HeaderValue) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::str::FromStr::from_str(&::std::string::String::from_utf8_lossy(value.as_bytes()))
+                }
+            }
+        },
+    };
+
+    quote_spanned! { span =>
+        #into_header
+
+        #from_header
+    }
+}
@@ -0,0 +1,156 @@
+use super::is_graphql_enum;
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::Ident;
+
+/// A GraphQL enum for strict enums, a string scalar that parses known values into their variant
+/// otherwise
+///
+/// Like the async-graphql integration, strict enums whose values aren't valid GraphQL names are
+/// exposed as a scalar too.
+pub fn derive(input: &Enum) -> TokenStream {
+    let juniper = input.crate_path("juniper");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let type_name = enum_name.to_string();
+
+    // scalars need to be 'static
+    if !input.generics.params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let parse = input.parse_str(quote_spanned! { span =>
+        ::std::format!("invalid value \"{}\" for {}", value, #type_name)
+    });
+    if is_graphql_enum(input) {
+        return graphql_enum(input, parse);
+    }
+
+    let alias = Ident::new(&format!("__StrNumJuniper{}", enum_name), span);
+
+    let from_input = Ident::new("__strnum_juniper_from_input", span);
+
+    quote_spanned! { span =>
+        const _: () = {
+            #[#juniper::graphql_scalar(
+                name = #type_name,
+                to_output_with = to_output,
+                from_input_with = #enum_name::#from_input,
+                parse_token(::std::string::String),
+            )]
+            type #alias = #enum_name;
+
+            fn to_output<S: #juniper::ScalarValue>(value: &#enum_name) -> #juniper::Value<S> {
+                #juniper::Value::scalar(::std::string::String::from(value.as_str()))
+            }
+        };
+
+        impl #enum_name {
+            #[doc(hidden)]
+            pub fn #from_input<S: #juniper::ScalarValue>(
+                input: &#juniper::InputValue<S>,
+            ) -> ::core::result::Result<Self, ::std::string::String> {
+                let value = match input.as_string_value() {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => return ::core::result::Result::Err(::std::format!("Expected `String`, found: {}", input)),
+                };
+                ::core::result::Result::Ok(#parse)
+            }
+        }
+    }
+}
+
+/// `GraphQLType` and the input and output traits for a GraphQL enum with the string values as enum
+/// values
+///
+/// Since these are string values, string literals are accepted as input too
+fn graphql_enum(input: &Enum, parse: Option<TokenStream>) -> TokenStream {
+    let juniper = input.crate_path("juniper");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let type_name = enum_name.to_string();
+
+    let enum_values = input.options.iter().map(|option| {
+        let name = &option.name;
+        let description = option.description.as_ref().map(|description| {
+            quote_spanned! { option.span => .description(#description) }
+        });
+        let deprecated = if option.deprecated {
+            Some(quote_spanned! { option.span => .deprecated(::core::option::Option::None) })
+        } else {
+            None
+        };
+        quote_spanned! { option.span =>
+            #juniper::meta::EnumValue::new(#name) #description #deprecated
+        }
+    });
+
+    quote_spanned! { span =>
+        impl<__S: #juniper::ScalarValue> #juniper::GraphQLType<__S> for #enum_name {
+            fn name(_: &()) -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(#type_name)
+            }
+
+            fn meta<'r>(info: &(), registry: &mut #juniper::Registry<'r, __S>) -> #juniper::meta::MetaType<'r, __S>
+            where
+                __S: 'r,
+            {
+                let values = [#(#enum_values),*];
+                registry.build_enum_type::<#enum_name>(info, &values).into_meta()
+            }
+        }
+
+        impl<__S: #juniper::ScalarValue> #juniper::GraphQLValue<__S> for #enum_name {
+            type Context = ();
+            type TypeInfo = ();
+
+            fn type_name<'i>(&self, info: &'i ()) -> ::core::option::Option<&'i str> {
+                <Self as #juniper::GraphQLType<__S>>::name(info)
+            }
+
+            fn resolve(
+                &self,
+                _: &(),
+                _: ::core::option::Option<&[#juniper::Selection<__S>]>,
+                _: &#juniper::Executor<(), __S>,
+            ) -> #juniper::ExecutionResult<__S> {
+                ::core::result::Result::Ok(#juniper::Value::scalar(::std::string::String::from(self.as_str())))
+            }
+        }
+
+        impl<__S: #juniper::ScalarValue + ::core::marker::Send + ::core::marker::Sync> #juniper::GraphQLValueAsync<__S> for #enum_name {
+            fn resolve_async<'a>(
+                &'a self,
+                info: &'a (),
+                selection_set: ::core::option::Option<&'a [#juniper::Selection<__S>]>,
+                executor: &'a #juniper::Executor<(), __S>,
+            ) -> #juniper::BoxFuture<'a, #juniper::ExecutionResult<__S>> {
+                let value = <Self as #juniper::GraphQLValue<__S>>::resolve(self, info, selection_set, executor);
+                ::std::boxed::Box::pin(async move { value })
+            }
+        }
+
+        impl<__S: #juniper::ScalarValue> #juniper::FromInputValue<__S> for #enum_name {
+            type Error = ::std::string::String;
+
+            fn from_input_value(input: &#juniper::InputValue<__S>) -> ::core::result::Result<Self, Self::Error> {
+                let value = match input.as_enum_value().or_else(|| input.as_string_value()) {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => return ::core::result::Result::Err(::std::format!("Expected `{}`, found: {}", #type_name, input)),
+                };
+                ::core::result::Result::Ok(#parse)
+            }
+        }
+
+        impl<__S: #juniper::ScalarValue> #juniper::ToInputValue<__S> for #enum_name {
+            fn to_input_value(&self) -> #juniper::InputValue<__S> {
+                #juniper::InputValue::enum_value(self.as_str())
+            }
+        }
+
+        impl<__S: #juniper::ScalarValue> #juniper::marker::IsInputType<__S> for #enum_name {}
+
+        impl<__S: #juniper::ScalarValue> #juniper::marker::IsOutputType<__S> for #enum_name {}
+    }
+}
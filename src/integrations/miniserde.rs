@@ -0,0 +1,71 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `Serialize` and `Deserialize` using the string value
+///
+/// miniserde deserializes through a visitor writing into a `Place`, which is defined together
+/// with a helper trait for parsing in an anonymous const to not clash with other enums.
+pub fn derive(input: &Enum) -> TokenStream {
+    let miniserde = input.crate_path("miniserde");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // number and nested variants have no borrowed string value
+    let value = if input
+        .options
+        .iter()
+        .any(|option| option.value_format().is_some())
+    {
+        quote_spanned! { span => ::std::borrow::Cow::Owned(::std::string::ToString::to_string(self)) }
+    } else {
+        quote_spanned! { span => ::std::borrow::Cow::Borrowed(self.as_str()) }
+    };
+    let serialize = quote_spanned! { span =>
+        impl #impl_generics #miniserde::Serialize for #enum_name #ty_generics #where_clause {
+            fn begin(&self) -> #miniserde::ser::Fragment {
+                #miniserde::ser::Fragment::Str(#value)
+            }
+        }
+    };
+
+    let deserialize = input
+        .parse_str(quote_spanned! { span => #miniserde::Error })
+        .map(|parse| {
+            quote_spanned! { span =>
+                const _: () = {
+                    #miniserde::make_place!(Place);
+
+                    trait StrNumParse: ::core::marker::Sized {
+                        fn parse(value: &str) -> #miniserde::Result<Self>;
+                    }
+
+                    impl #impl_generics StrNumParse for #enum_name #ty_generics #where_clause {
+                        fn parse(value: &str) -> #miniserde::Result<Self> {
+                            ::core::result::Result::Ok(#parse)
+                        }
+                    }
+
+                    impl #impl_generics #miniserde::de::Visitor for Place<#enum_name #ty_generics> #where_clause {
+                        fn string(&mut self, value: &str) -> #miniserde::Result<()> {
+                            self.out = ::core::option::Option::Some(<#enum_name #ty_generics as StrNumParse>::parse(value)?);
+                            ::core::result::Result::Ok(())
+                        }
+                    }
+
+                    impl #impl_generics #miniserde::Deserialize for #enum_name #ty_generics #where_clause {
+                        fn begin(out: &mut ::core::option::Option<Self>) -> &mut dyn #miniserde::de::Visitor {
+                            Place::new(out)
+                        }
+                    }
+                };
+            }
+        });
+
+    quote_spanned! { span =>
+        #serialize
+
+        #deserialize
+    }
+}
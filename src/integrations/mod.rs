@@ -0,0 +1,93 @@
+//! Implementations for traits from other crates, each behind a feature of the same name
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{GenericParam, Generics, Ident, Lifetime, LifetimeDef, TypeParam};
+
+#[cfg(any(feature = "async-graphql", feature = "juniper"))]
+use crate::Enum;
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "dialoguer")]
+pub mod dialoguer;
+#[cfg(feature = "diesel")]
+pub mod diesel;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "juniper")]
+pub mod juniper;
+#[cfg(feature = "miniserde")]
+pub mod miniserde;
+#[cfg(feature = "postgres-types")]
+pub mod postgres_types;
+#[cfg(feature = "pyo3")]
+pub mod pyo3;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "sea-orm")]
+pub mod sea_orm;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm_bindgen;
+
+/// The generics of the enum with additional lifetime and type parameters
+pub(crate) fn with_params(generics: &Generics, lifetimes: &[&str], types: &[&str]) -> Generics {
+    let mut generics = generics.clone();
+    for lifetime in lifetimes.iter().rev() {
+        let lifetime = LifetimeDef::new(Lifetime::new(lifetime, Span::call_site()));
+        generics.params.insert(0, GenericParam::Lifetime(lifetime));
+    }
+    for ty in types {
+        let ident = Ident::new(ty, Span::call_site());
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident)));
+    }
+    generics
+}
+
+/// Whether the enum can be exposed as a GraphQL enum instead of a string scalar
+///
+/// Only strict enums without skipped variants qualify, and all values have to be valid GraphQL
+/// names
+#[cfg(any(feature = "async-graphql", feature = "juniper"))]
+pub(crate) fn is_graphql_enum(input: &Enum) -> bool {
+    let is_name = |name: &str| {
+        let mut bytes = name.bytes();
+        bytes
+            .next()
+            .map_or(false, |b| b == b'_' || b.is_ascii_alphabetic())
+            && bytes.all(|b| b == b'_' || b.is_ascii_alphanumeric())
+    };
+    !input.accepts_unknown()
+        && input.skipped.is_empty()
+        && input.options.iter().all(|option| is_name(&option.name))
+}
+
+/// The predicates of the where clause of the enum, with a trailing comma so more can be added
+#[allow(dead_code)]
+fn where_predicates(generics: &Generics) -> TokenStream {
+    match &generics.where_clause {
+        Some(where_clause) => {
+            let predicates = where_clause.predicates.iter();
+            quote! { #(#predicates ,)* }
+        }
+        None => TokenStream::new(),
+    }
+}
@@ -0,0 +1,88 @@
+use super::with_params;
+use crate::{Enum, FallbackType};
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::Lifetime;
+
+/// `ToSql` and `FromSql` accepting both text types and Postgres enum types
+pub fn derive(input: &Enum) -> TokenStream {
+    let postgres_types = input.crate_path("postgres_types");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let accepts = quote_spanned! { span =>
+        fn accepts(ty: &#postgres_types::Type) -> bool {
+            match ty.kind() {
+                #postgres_types::Kind::Enum(_) => true,
+                _ => <&str as #postgres_types::ToSql>::accepts(ty),
+            }
+        }
+    };
+
+    let to_sql = quote_spanned! { span =>
+        impl #impl_generics #postgres_types::ToSql for #enum_name #ty_generics #where_clause {
+            fn to_sql(
+                &self,
+                ty: &#postgres_types::Type,
+                out: &mut #postgres_types::private::BytesMut,
+            ) -> ::core::result::Result<
+                #postgres_types::IsNull,
+                ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>,
+            > {
+                <&str as #postgres_types::ToSql>::to_sql(&self.as_str(), ty, out)
+            }
+
+            #accepts
+
+            #postgres_types::to_sql_checked!();
+        }
+    };
+
+    // borrowed fallback options borrow from the raw value
+    let borrowed_generics;
+    let (from_sql_generics, lifetime, parse) = match input.fallback_type {
+        Some(FallbackType::Cow(lifetime)) | Some(FallbackType::Str(lifetime)) => (
+            impl_generics,
+            lifetime.clone(),
+            Some(quote_spanned! { span =>
+                <Self as ::core::convert::From<&#lifetime str>>::from(value)
+            }),
+        ),
+        _ => {
+            borrowed_generics = with_params(input.generics, &["'__a"], &[]);
+            let type_name = enum_name.to_string();
+            let parse = input.parse_str(quote_spanned! { span =>
+                ::std::format!("invalid value \"{}\" for {}", value, #type_name).into()
+            });
+            (
+                borrowed_generics.split_for_impl().0,
+                Lifetime::new("'__a", Span::call_site()),
+                parse,
+            )
+        }
+    };
+
+    let from_sql = quote_spanned! { span =>
+        impl #from_sql_generics #postgres_types::FromSql<#lifetime> for #enum_name #ty_generics #where_clause {
+            fn from_sql(
+                ty: &#postgres_types::Type,
+                raw: &#lifetime [u8],
+            ) -> ::core::result::Result<
+                Self,
+                ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>,
+            > {
+                let value = <&#lifetime str as #postgres_types::FromSql>::from_sql(ty, raw)?;
+                ::core::result::Result::Ok(#parse)
+            }
+
+            #accepts
+        }
+    };
+
+    quote_spanned! { span =>
+        #to_sql
+
+        #from_sql
+    }
+}
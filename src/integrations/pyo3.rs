@@ -0,0 +1,62 @@
+use super::with_params;
+use crate::{Enum, FallbackType};
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::Lifetime;
+
+/// `FromPyObject` accepting python strings, `IntoPy` and `ToPyObject` converting into one
+pub fn derive(input: &Enum) -> TokenStream {
+    let pyo3 = input.crate_path("pyo3");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // borrowed fallback options borrow from the python string
+    let borrowed_generics;
+    let (extract_generics, lifetime, parse) = match input.fallback_type {
+        Some(FallbackType::Cow(lifetime)) | Some(FallbackType::Str(lifetime)) => (
+            impl_generics.clone(),
+            lifetime.clone(),
+            Some(quote_spanned! { span =>
+                <Self as ::core::convert::From<&#lifetime str>>::from(value)
+            }),
+        ),
+        _ => {
+            borrowed_generics = with_params(input.generics, &["'source"], &[]);
+            let type_name = enum_name.to_string();
+            let parse = input.parse_str(quote_spanned! { span =>
+                #pyo3::exceptions::PyValueError::new_err(::std::format!(
+                    "invalid value \"{}\" for {}",
+                    value,
+                    #type_name
+                ))
+            });
+            (
+                borrowed_generics.split_for_impl().0,
+                Lifetime::new("'source", Span::call_site()),
+                parse,
+            )
+        }
+    };
+
+    quote_spanned! { span =>
+        impl #extract_generics #pyo3::FromPyObject<#lifetime> for #enum_name #ty_generics #where_clause {
+            fn extract(object: &#lifetime #pyo3::PyAny) -> #pyo3::PyResult<Self> {
+                let value = <&#lifetime str as #pyo3::FromPyObject>::extract(object)?;
+                ::core::result::Result::Ok(#parse)
+            }
+        }
+
+        impl #impl_generics #pyo3::IntoPy<#pyo3::PyObject> for #enum_name #ty_generics #where_clause {
+            fn into_py(self, py: #pyo3::Python<'_>) -> #pyo3::PyObject {
+                #pyo3::IntoPy::into_py(self.as_str(), py)
+            }
+        }
+
+        impl #impl_generics #pyo3::ToPyObject for #enum_name #ty_generics #where_clause {
+            fn to_object(&self, py: #pyo3::Python<'_>) -> #pyo3::PyObject {
+                #pyo3::ToPyObject::to_object(self.as_str(), py)
+            }
+        }
+    }
+}
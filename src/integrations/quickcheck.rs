@@ -0,0 +1,91 @@
+use crate::Enum;
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, quote_spanned};
+
+/// `Arbitrary` picking one of the known variants, or occasionally an arbitrary string for enums
+/// with a fallback option
+///
+/// Values shrink towards the variants declared before them, fallback values shrink to all known
+/// variants followed by the shrunk string.
+pub fn derive(input: &Enum) -> TokenStream {
+    let quickcheck = input.crate_path("quickcheck");
+    let enum_name = input.name;
+    let span = enum_name.span();
+
+    // quickcheck requires `'static` types
+    if !input.generics.params.is_empty() {
+        return TokenStream::new();
+    }
+
+    let fallback = match input.fallback_type {
+        Some(_) => quote_spanned! { span =>
+            if Self::COUNT == 0 || g.choose(&[true, false, false, false]) == ::core::option::Option::Some(&true) {
+                let value = <::std::string::String as #quickcheck::Arbitrary>::arbitrary(g);
+                return Self::from(value.as_str());
+            }
+        },
+        None => TokenStream::new(),
+    };
+
+    let shrink_arms = input
+        .options
+        .iter()
+        .filter(|option| !option.catch_all)
+        .enumerate()
+        .map(|(index, option)| {
+            let span = option.span;
+            let ident = &option.ident;
+            let index = Literal::usize_unsuffixed(index);
+            quote_spanned! { span =>
+                #enum_name::#ident => ::std::boxed::Box::new(Self::iter().take(#index))
+            }
+        })
+        .chain(input.options.iter().filter(|option| option.catch_all).map(|option| {
+            let span = option.span;
+            // values of pattern variants can't be shrunk without losing the match
+            if option.pattern.is_some() {
+                let binding = option.with_field(enum_name, quote! { _ });
+                return quote_spanned! { span =>
+                    #binding => ::std::boxed::Box::new(Self::iter())
+                };
+            }
+            let binding = option.with_field(enum_name, quote! { value });
+            // a display catch-all doesn't have to deref to `str`
+            let value = if option.display {
+                quote! { ::std::string::ToString::to_string(value) }
+            } else {
+                quote! { ::std::string::String::from(&**value) }
+            };
+            quote_spanned! { span =>
+                #binding => {
+                    let value = #value;
+                    ::std::boxed::Box::new(Self::iter().chain(
+                        #quickcheck::Arbitrary::shrink(&value).map(|value| Self::from(value.as_str())),
+                    ))
+                }
+            }
+        }))
+        .chain(input.skipped.iter().map(|option| {
+            let span = option.span;
+            let ident = &option.ident;
+            quote_spanned! { span =>
+                #enum_name::#ident { .. } => ::std::boxed::Box::new(::core::iter::empty())
+            }
+        }));
+
+    quote_spanned! { span =>
+        impl #quickcheck::Arbitrary for #enum_name {
+            fn arbitrary(g: &mut #quickcheck::Gen) -> Self {
+                #fallback
+                let index = <usize as #quickcheck::Arbitrary>::arbitrary(g) % Self::COUNT;
+                Self::iter().nth(index).unwrap()
+            }
+
+            fn shrink(&self) -> ::std::boxed::Box<dyn ::core::iter::Iterator<Item = Self>> {
+                match self {
+                    #(#shrink_arms ,)*
+                }
+            }
+        }
+    }
+}
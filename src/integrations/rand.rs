@@ -0,0 +1,22 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `Distribution<Enum>` for `Standard`, uniform over the known variants
+pub fn derive(input: &Enum) -> TokenStream {
+    let rand = input.crate_path("rand");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote_spanned! { span =>
+        impl #impl_generics #rand::distributions::Distribution<#enum_name #ty_generics>
+            for #rand::distributions::Standard #where_clause
+        {
+            fn sample<__R: #rand::Rng + ?::core::marker::Sized>(&self, rng: &mut __R) -> #enum_name #ty_generics {
+                let index = rng.gen_range(0..<#enum_name #ty_generics>::COUNT);
+                <#enum_name #ty_generics>::iter().nth(index).unwrap()
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `ToSql` and `FromSql` storing the enum as text
+pub fn derive(input: &Enum) -> TokenStream {
+    let rusqlite = input.crate_path("rusqlite");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let to_sql = quote_spanned! { span =>
+        impl #impl_generics #rusqlite::types::ToSql for #enum_name #ty_generics #where_clause {
+            fn to_sql(&self) -> #rusqlite::Result<#rusqlite::types::ToSqlOutput<'_>> {
+                ::core::result::Result::Ok(#rusqlite::types::ToSqlOutput::from(self.as_str()))
+            }
+        }
+    };
+
+    let type_name = enum_name.to_string();
+    let from_sql = input
+        .parse_str(quote_spanned! { span =>
+            #rusqlite::types::FromSqlError::Other(
+                ::std::format!("invalid value \"{}\" for {}", value, #type_name).into(),
+            )
+        })
+        .map(|parse| {
+            quote_spanned! { span =>
+                impl #impl_generics #rusqlite::types::FromSql for #enum_name #ty_generics #where_clause {
+                    fn column_result(
+                        value: #rusqlite::types::ValueRef<'_>,
+                    ) -> #rusqlite::types::FromSqlResult<Self> {
+                        let value = value.as_str()?;
+                        ::core::result::Result::Ok(#parse)
+                    }
+                }
+            }
+        });
+
+    quote_spanned! { span =>
+        #to_sql
+
+        #from_sql
+    }
+}
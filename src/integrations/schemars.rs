@@ -0,0 +1,37 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// A string schema, listing the known values if there are no catch-all variants
+pub fn derive(input: &Enum) -> TokenStream {
+    let schemars = input.crate_path("schemars");
+    let enum_name = input.name;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let schema_name = enum_name.to_string();
+
+    let enum_values = if input.accepts_unknown() {
+        quote_spanned! { enum_name.span() => ::core::option::Option::None }
+    } else {
+        let names = input.known_names();
+        let names = names.iter();
+        quote_spanned! { enum_name.span() =>
+            ::core::option::Option::Some(::std::vec![#(#names.into()),*])
+        }
+    };
+
+    quote_spanned! { enum_name.span() =>
+        impl #impl_generics #schemars::JsonSchema for #enum_name #ty_generics #where_clause {
+            fn schema_name() -> ::std::string::String {
+                ::std::string::String::from(#schema_name)
+            }
+
+            fn json_schema(_: &mut #schemars::gen::SchemaGenerator) -> #schemars::schema::Schema {
+                #schemars::schema::Schema::Object(#schemars::schema::SchemaObject {
+                    instance_type: ::core::option::Option::Some(#schemars::schema::InstanceType::String.into()),
+                    enum_values: #enum_values,
+                    ..::core::default::Default::default()
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `Into<Value>`, `TryGetable`, `ValueType` and `Nullable` so the enum can be used as column type
+pub fn derive(input: &Enum) -> TokenStream {
+    let sea_orm = input.crate_path("sea_orm");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let type_name = enum_name.to_string();
+
+    let into_value = quote_spanned! { span =>
+        impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #sea_orm::Value #where_clause {
+            fn from(value: #enum_name #ty_generics) -> Self {
+                #sea_orm::Value::from(::std::string::String::from(value))
+            }
+        }
+
+        impl #impl_generics #sea_orm::sea_query::Nullable for #enum_name #ty_generics #where_clause {
+            fn null() -> #sea_orm::Value {
+                <::std::string::String as #sea_orm::sea_query::Nullable>::null()
+            }
+        }
+    };
+
+    let try_getable = input
+        .parse_str(quote_spanned! { span =>
+            #sea_orm::TryGetError::DbErr(#sea_orm::DbErr::Type(::std::format!(
+                "invalid value \"{}\" for {}",
+                value,
+                #type_name
+            )))
+        })
+        .map(|parse| {
+            quote_spanned! { span =>
+                impl #impl_generics #sea_orm::TryGetable for #enum_name #ty_generics #where_clause {
+                    fn try_get_by<__I: #sea_orm::ColIdx>(
+                        res: &#sea_orm::QueryResult,
+                        index: __I,
+                    ) -> ::core::result::Result<Self, #sea_orm::TryGetError> {
+                        let value = <::std::string::String as #sea_orm::TryGetable>::try_get_by(res, index)?;
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                }
+            }
+        });
+
+    let value_type = input
+        .parse_str(quote_spanned! { span => #sea_orm::sea_query::ValueTypeErr })
+        .map(|parse| {
+            quote_spanned! { span =>
+                impl #impl_generics #sea_orm::sea_query::ValueType for #enum_name #ty_generics #where_clause {
+                    fn try_from(
+                        value: #sea_orm::Value,
+                    ) -> ::core::result::Result<Self, #sea_orm::sea_query::ValueTypeErr> {
+                        let value = <::std::string::String as #sea_orm::sea_query::ValueType>::try_from(value)?;
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+
+                    fn type_name() -> ::std::string::String {
+                        ::std::string::String::from(#type_name)
+                    }
+
+                    fn array_type() -> #sea_orm::sea_query::ArrayType {
+                        <::std::string::String as #sea_orm::sea_query::ValueType>::array_type()
+                    }
+
+                    fn column_type() -> #sea_orm::sea_query::ColumnType {
+                        <::std::string::String as #sea_orm::sea_query::ValueType>::column_type()
+                    }
+                }
+            }
+        });
+
+    quote_spanned! { span =>
+        #into_value
+
+        #try_getable
+
+        #value_type
+    }
+}
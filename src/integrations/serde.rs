@@ -0,0 +1,373 @@
+use crate::{string_path, Enum, FallbackType, SerdeRepr};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote_spanned;
+use syn::{GenericParam, Lifetime, LifetimeDef};
+
+pub fn derive(input: &Enum) -> TokenStream {
+    let serialize = serialize(input);
+    let deserialize = deserialize(input);
+
+    quote_spanned! { input.name.span() =>
+        #serialize
+
+        #deserialize
+    }
+}
+
+/// Serialize as the string value
+///
+/// Number and nested variants have no borrowed string value, enums with these variants are
+/// serialized using `Display`
+fn serialize(input: &Enum) -> TokenStream {
+    let serde = input.crate_path("serde");
+    let enum_name = input.name;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let serialize = if input.serde_repr == SerdeRepr::Index {
+        serialize_index(input)
+    } else if input
+        .options
+        .iter()
+        .any(|option| option.value_format().is_some())
+    {
+        quote_spanned! { enum_name.span() => serializer.collect_str(self) }
+    } else {
+        quote_spanned! { enum_name.span() => serializer.serialize_str(self.as_str()) }
+    };
+
+    quote_spanned! { enum_name.span() =>
+        impl #impl_generics #serde::Serialize for #enum_name #ty_generics #where_clause {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: #serde::Serializer,
+            {
+                #serialize
+            }
+        }
+    }
+}
+
+/// Serialize known variants as unit variant and the catch-all variants as newtype variant
+///
+/// The catch-all variants all share the variant following the known variants
+fn serialize_index(input: &Enum) -> TokenStream {
+    let serde = input.crate_path("serde");
+    let enum_name = input.name;
+    let name = enum_name.to_string();
+    let other_tag = other_tag(input);
+    let other_index = Literal::u32_unsuffixed(input.known_names().len() as u32);
+
+    let known_arms = input
+        .options
+        .iter()
+        .filter(|option| !option.catch_all)
+        .enumerate()
+        .map(|(index, option)| {
+            let ident = &option.ident;
+            let variant = &option.name;
+            let index = Literal::u32_unsuffixed(index as u32);
+            quote_spanned! { option.span =>
+                #enum_name::#ident => serializer.serialize_unit_variant(#name, #index, #variant)
+            }
+        });
+    let other_arms = input
+        .options
+        .iter()
+        .filter(|option| option.catch_all)
+        .map(|option| {
+            let ident = &option.ident;
+            let value = if option.value_format().is_some() {
+                quote_spanned! { option.span => &::core::format_args!("{}", self) }
+            } else {
+                quote_spanned! { option.span => self.as_str() }
+            };
+            quote_spanned! { option.span =>
+                #enum_name::#ident { .. } => serializer.serialize_newtype_variant(#name, #other_index, #other_tag, #value)
+            }
+        });
+    let skipped_arms = input.skipped.iter().map(|option| {
+        let ident = &option.ident;
+        let message = format!("{}::{} can't be serialized", enum_name, ident);
+        quote_spanned! { option.span =>
+            #enum_name::#ident { .. } => ::core::result::Result::Err(<__S::Error as #serde::ser::Error>::custom(#message))
+        }
+    });
+
+    quote_spanned! { enum_name.span() =>
+        match self {
+            #(#known_arms ,)*
+            #(#other_arms ,)*
+            #(#skipped_arms ,)*
+        }
+    }
+}
+
+/// The serde variant name of the catch-all variants, the name of the first catch-all variant
+fn other_tag(input: &Enum) -> String {
+    input
+        .options
+        .iter()
+        .find(|option| option.catch_all)
+        .map_or_else(|| String::from("Other"), |option| option.ident.to_string())
+}
+
+/// Deserialize from a string using the `From` or `TryFrom` implementations
+///
+/// The visitor is generic over the enum so it can be defined inside the `deserialize` function
+/// regardless of the generics of the enum.
+fn deserialize(input: &Enum) -> TokenStream {
+    let serde = input.crate_path("serde");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let string_type = string_path();
+
+    // borrowed catch-all values borrow from the deserializer
+    let mut de_lifetime = LifetimeDef::new(Lifetime::new("'de", Span::call_site()));
+    match input.fallback_type {
+        Some(FallbackType::Cow(lifetime)) | Some(FallbackType::Str(lifetime)) => {
+            de_lifetime.bounds.push(lifetime.clone());
+        }
+        _ => {}
+    }
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .insert(0, GenericParam::Lifetime(de_lifetime));
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let visitor = match input.fallback_type {
+        Some(fallback_type) => {
+            let visit_owned = if fallback_type.is_owned() {
+                quote_spanned! { span =>
+                    fn visit_str<E>(self, value: &str) -> ::core::result::Result<T, E>
+                    where
+                        E: #serde::de::Error,
+                    {
+                        ::core::result::Result::Ok(<T as ::core::convert::From<#string_type>>::from(#string_type::from(value)))
+                    }
+
+                    fn visit_string<E>(self, value: #string_type) -> ::core::result::Result<T, E>
+                    where
+                        E: #serde::de::Error,
+                    {
+                        ::core::result::Result::Ok(<T as ::core::convert::From<#string_type>>::from(value))
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+            let owned_bound = if fallback_type.is_owned() {
+                quote_spanned! { span => + ::core::convert::From<#string_type> }
+            } else {
+                TokenStream::new()
+            };
+
+            quote_spanned! { span =>
+                struct StrNumVisitor<'a, T>(::core::marker::PhantomData<(&'a (), T)>);
+
+                impl<'de: 'a, 'a, T> #serde::de::Visitor<'de> for StrNumVisitor<'a, T>
+                where
+                    T: ::core::convert::From<&'a str> #owned_bound,
+                {
+                    type Value = T;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        formatter.write_str("a string")
+                    }
+
+                    fn visit_borrowed_str<E>(self, value: &'de str) -> ::core::result::Result<T, E>
+                    where
+                        E: #serde::de::Error,
+                    {
+                        ::core::result::Result::Ok(<T as ::core::convert::From<&'a str>>::from(value))
+                    }
+
+                    #visit_owned
+                }
+            }
+        }
+        None => {
+            let names = input.known_names();
+            let expecting = format!(
+                "one of {}",
+                names
+                    .iter()
+                    .map(|name| format!("\"{}\"", name.escape_default()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let names = names.iter();
+
+            quote_spanned! { span =>
+                struct StrNumVisitor<'a, T>(::core::marker::PhantomData<(&'a (), T)>);
+
+                impl<'de, 'a, T> #serde::de::Visitor<'de> for StrNumVisitor<'a, T>
+                where
+                    T: for<'s> ::core::convert::TryFrom<&'s str>,
+                {
+                    type Value = T;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> ::core::result::Result<T, E>
+                    where
+                        E: #serde::de::Error,
+                    {
+                        <T as ::core::convert::TryFrom<&str>>::try_from(value).map_err(|_| E::unknown_variant(value, &[#(#names),*]))
+                    }
+                }
+            }
+        }
+    };
+
+    let deserialize = if input.serde_repr == SerdeRepr::Index {
+        deserialize_index(input)
+    } else {
+        quote_spanned! { span =>
+            deserializer.deserialize_str(StrNumVisitor(::core::marker::PhantomData))
+        }
+    };
+
+    quote_spanned! { span =>
+        impl #impl_generics #serde::Deserialize<'de> for #enum_name #ty_generics #where_clause {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                #visitor
+
+                #deserialize
+            }
+        }
+    }
+}
+
+/// Deserialize a serde enum, known variants by their ordinal and the catch-all variant from its
+/// string value using the string visitor
+///
+/// The enum visitor returns either the ordinal of a known variant or the parsed catch-all value
+fn deserialize_index(input: &Enum) -> TokenStream {
+    let serde = input.crate_path("serde");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let name = enum_name.to_string();
+    let known_names = input.known_names();
+    let known_count = known_names.len();
+    let mut variants = known_names
+        .iter()
+        .map(|name| name.as_str())
+        .collect::<Vec<_>>();
+    let other_tag = other_tag(input);
+    if input.accepts_unknown() {
+        variants.push(&other_tag);
+    }
+    let expecting = format!("enum {}", name);
+
+    quote_spanned! { span =>
+        const VARIANTS: &[&str] = &[#(#variants),*];
+
+        struct StrNumTag;
+
+        impl<'de> #serde::de::DeserializeSeed<'de> for StrNumTag {
+            type Value = usize;
+
+            fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<usize, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_identifier(self)
+            }
+        }
+
+        impl<'de> #serde::de::Visitor<'de> for StrNumTag {
+            type Value = usize;
+
+            fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                formatter.write_str("variant identifier")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> ::core::result::Result<usize, E>
+            where
+                E: #serde::de::Error,
+            {
+                if value < VARIANTS.len() as u64 {
+                    ::core::result::Result::Ok(value as usize)
+                } else {
+                    ::core::result::Result::Err(E::invalid_value(
+                        #serde::de::Unexpected::Unsigned(value),
+                        &"a variant index",
+                    ))
+                }
+            }
+
+            fn visit_str<E>(self, value: &str) -> ::core::result::Result<usize, E>
+            where
+                E: #serde::de::Error,
+            {
+                VARIANTS
+                    .iter()
+                    .position(|variant| *variant == value)
+                    .ok_or_else(|| E::unknown_variant(value, VARIANTS))
+            }
+        }
+
+        struct StrNumSeed<'a, T>(::core::marker::PhantomData<(&'a (), T)>);
+
+        impl<'de, 'a, T> #serde::de::DeserializeSeed<'de> for StrNumSeed<'a, T>
+        where
+            StrNumVisitor<'a, T>: #serde::de::Visitor<'de, Value = T>,
+        {
+            type Value = T;
+
+            fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<T, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_str(StrNumVisitor(::core::marker::PhantomData))
+            }
+        }
+
+        struct StrNumEnumVisitor<'a, T>(::core::marker::PhantomData<(&'a (), T)>);
+
+        impl<'de, 'a, T> #serde::de::Visitor<'de> for StrNumEnumVisitor<'a, T>
+        where
+            StrNumVisitor<'a, T>: #serde::de::Visitor<'de, Value = T>,
+        {
+            type Value = ::core::result::Result<usize, T>;
+
+            fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                formatter.write_str(#expecting)
+            }
+
+            fn visit_enum<__A>(self, data: __A) -> ::core::result::Result<Self::Value, __A::Error>
+            where
+                __A: #serde::de::EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(StrNumTag)?;
+                if index < #known_count {
+                    #serde::de::VariantAccess::unit_variant(variant)?;
+                    ::core::result::Result::Ok(::core::result::Result::Ok(index))
+                } else {
+                    #serde::de::VariantAccess::newtype_variant_seed(
+                        variant,
+                        StrNumSeed(::core::marker::PhantomData),
+                    )
+                    .map(::core::result::Result::Err)
+                }
+            }
+        }
+
+        match deserializer.deserialize_enum(
+            #name,
+            VARIANTS,
+            StrNumEnumVisitor(::core::marker::PhantomData),
+        )? {
+            ::core::result::Result::Ok(index) => Self::from_ordinal(index).ok_or_else(|| {
+                <__D::Error as #serde::de::Error>::custom("variant index out of range")
+            }),
+            ::core::result::Result::Err(value) => ::core::result::Result::Ok(value),
+        }
+    }
+}
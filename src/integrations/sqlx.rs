@@ -0,0 +1,123 @@
+use super::{where_predicates, with_params};
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// `Type`, `Encode` and `Decode` for any database that supports strings, stored as text
+///
+/// Known values are encoded as `&'static str`, only other values allocate a `String` since most
+/// databases can't borrow from the enum for the lifetime of the query.
+pub fn derive(input: &Enum) -> TokenStream {
+    let sqlx = input.crate_path("sqlx");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let db_generics = with_params(input.generics, &[], &["__DB"]);
+    let (db_impl_generics, _, _) = db_generics.split_for_impl();
+    let encode_generics = with_params(input.generics, &["'__q"], &["__DB"]);
+    let (encode_impl_generics, _, _) = encode_generics.split_for_impl();
+    let decode_generics = with_params(input.generics, &["'__r"], &["__DB"]);
+    let (decode_impl_generics, _, _) = decode_generics.split_for_impl();
+
+    let where_predicates = where_predicates(input.generics);
+
+    let type_impl = quote_spanned! { span =>
+        impl #db_impl_generics #sqlx::Type<__DB> for #enum_name #ty_generics
+        where
+            #where_predicates
+            __DB: #sqlx::Database,
+            str: #sqlx::Type<__DB>,
+        {
+            fn type_info() -> <__DB as #sqlx::Database>::TypeInfo {
+                <str as #sqlx::Type<__DB>>::type_info()
+            }
+
+            fn compatible(ty: &<__DB as #sqlx::Database>::TypeInfo) -> bool {
+                <str as #sqlx::Type<__DB>>::compatible(ty)
+            }
+        }
+    };
+
+    // known values are `'static` and can be encoded without allocating, the catch-all and
+    // skipped variants are formatted into a `String`
+    let known_arms = input
+        .options
+        .iter()
+        .filter(|option| !option.catch_all)
+        .map(|option| {
+            let ident = &option.ident;
+            let name = &option.name;
+            quote_spanned! { option.span =>
+                #enum_name::#ident => #name
+            }
+        });
+    let (other_arm, other_bound) = if input.accepts_unknown() || !input.skipped.is_empty() {
+        (
+            quote_spanned! { span =>
+                _ => {
+                    return <::std::string::String as #sqlx::Encode<'__q, __DB>>::encode(
+                        ::std::string::ToString::to_string(self),
+                        buf,
+                    )
+                }
+            },
+            quote_spanned! { span =>
+                ::std::string::String: #sqlx::Encode<'__q, __DB>,
+            },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    let encode_impl = quote_spanned! { span =>
+        impl #encode_impl_generics #sqlx::Encode<'__q, __DB> for #enum_name #ty_generics
+        where
+            #where_predicates
+            __DB: #sqlx::Database,
+            &'__q str: #sqlx::Encode<'__q, __DB>,
+            #other_bound
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as #sqlx::Database>::ArgumentBuffer<'__q>,
+            ) -> ::core::result::Result<#sqlx::encode::IsNull, #sqlx::error::BoxDynError> {
+                let value: &'static str = match self {
+                    #(#known_arms ,)*
+                    #other_arm
+                };
+                <&'__q str as #sqlx::Encode<'__q, __DB>>::encode(value, buf)
+            }
+        }
+    };
+
+    let type_name = enum_name.to_string();
+    let decode_impl = input
+        .parse_str(quote_spanned! { span =>
+            ::std::format!("invalid value \"{}\" for {}", value, #type_name).into()
+        })
+        .map(|parse| {
+            quote_spanned! { span =>
+                impl #decode_impl_generics #sqlx::Decode<'__r, __DB> for #enum_name #ty_generics
+                where
+                    #where_predicates
+                    __DB: #sqlx::Database,
+                    &'__r str: #sqlx::Decode<'__r, __DB>,
+                {
+                    fn decode(
+                        value: <__DB as #sqlx::Database>::ValueRef<'__r>,
+                    ) -> ::core::result::Result<Self, #sqlx::error::BoxDynError> {
+                        let value = <&'__r str as #sqlx::Decode<'__r, __DB>>::decode(value)?;
+                        ::core::result::Result::Ok(#parse)
+                    }
+                }
+            }
+        });
+
+    quote_spanned! { span =>
+        #type_impl
+
+        #encode_impl
+
+        #decode_impl
+    }
+}
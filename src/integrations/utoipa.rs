@@ -0,0 +1,50 @@
+use super::with_params;
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// A string schema, listing the known values as enum values if there are no catch-all variants or
+/// in the description otherwise
+pub fn derive(input: &Enum) -> TokenStream {
+    let utoipa = input.crate_path("utoipa");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let schema_generics = with_params(input.generics, &["'__s"], &[]);
+    let (schema_impl_generics, _, _) = schema_generics.split_for_impl();
+    let schema_name = enum_name.to_string();
+
+    let names = input.known_names();
+    let values = if input.accepts_unknown() {
+        let description = format!(
+            "Known values: {}, other values are accepted too",
+            names
+                .iter()
+                .map(|name| format!("\"{}\"", name.escape_default()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        quote_spanned! { span =>
+            .description(::core::option::Option::Some(#description))
+        }
+    } else {
+        let names = names.iter();
+        quote_spanned! { span =>
+            .enum_values(::core::option::Option::Some([#(#names),*]))
+        }
+    };
+
+    quote_spanned! { span =>
+        impl #schema_impl_generics #utoipa::ToSchema<'__s> for #enum_name #ty_generics #where_clause {
+            fn schema() -> (&'__s str, #utoipa::openapi::RefOr<#utoipa::openapi::schema::Schema>) {
+                (
+                    #schema_name,
+                    #utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(#utoipa::openapi::SchemaType::String)
+                        #values
+                        .into(),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Enum;
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+
+/// Conversions into a `JsValue` string and back
+///
+/// Enums with an owned fallback option can be converted from any `JsValue`, other enums only
+/// implement `TryFrom<JsValue>` and return an error for non-string or unknown values.
+pub fn derive(input: &Enum) -> TokenStream {
+    let wasm_bindgen = input.crate_path("wasm_bindgen");
+    let enum_name = input.name;
+    let span = enum_name.span();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let type_name = enum_name.to_string();
+
+    let into_js = quote_spanned! { span =>
+        impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #wasm_bindgen::JsValue #where_clause {
+            fn from(value: #enum_name #ty_generics) -> Self {
+                #wasm_bindgen::JsValue::from_str(value.as_str())
+            }
+        }
+    };
+
+    let from_js = match input.fallback_type {
+        Some(fallback_type) if fallback_type.is_owned() => {
+            let parse = input.parse_str(TokenStream::new());
+            quote_spanned! { span =>
+                impl #impl_generics ::core::convert::From<#wasm_bindgen::JsValue> for #enum_name #ty_generics #where_clause {
+                    fn from(value: #wasm_bindgen::JsValue) -> Self {
+                        let value = value.as_string().unwrap_or_else(|| value.as_debug_string());
+                        let value = value.as_str();
+                        #parse
+                    }
+                }
+            }
+        }
+        Some(_) => TokenStream::new(),
+        None => {
+            let parse = input.parse_str(quote_spanned! { span =>
+                #wasm_bindgen::JsValue::from_str(&::std::format!(
+                    "invalid value \"{}\" for {}",
+                    value,
+                    #type_name
+                ))
+            });
+            quote_spanned! { span =>
+                impl #impl_generics ::core::convert::TryFrom<#wasm_bindgen::JsValue> for #enum_name #ty_generics #where_clause {
+                    type Error = #wasm_bindgen::JsValue;
+
+                    fn try_from(value: #wasm_bindgen::JsValue) -> ::core::result::Result<Self, Self::Error> {
+                        let value = match value.as_string() {
+                            ::core::option::Option::Some(value) => value,
+                            ::core::option::Option::None => return ::core::result::Result::Err(value),
+                        };
+                        let value = value.as_str();
+                        ::core::result::Result::Ok(#parse)
+                    }
+                }
+            }
+        }
+    };
+
+    quote_spanned! { span =>
+        #into_js
+        #from_js
+    }
+}
@@ -4,12 +4,415 @@
 //! # StrNum
 //!
 //! StrNum can be derived for enum that contain a number of unit fields for every string option and
-//! optionally one field containing a `String` as fallback option.
+//! optionally one field containing a `String` as fallback option. The fallback option can hold its
+//! value in a tuple variant like `Other(String)` or in a single named field like
+//! `Other { value: String }`.
+//!
+//! The fallback option can also hold a `Cow<'a, str>`, in which case `From<&'a str>` borrows the
+//! input instead of allocating a new `String`. For zero-copy parsing the fallback option can hold
+//! a `&'a str`, only `From<&'a str>` is implemented for these enums since the fallback can't be
+//! created from an owned `String`.
+//!
+//! Other types like `Box<str>`, `Arc<str>` or small string types can be used for the fallback
+//! option too, as long as they implement `From<String>`, `From<&str>`, `Display` and
+//! `Deref<Target = str>`. The fallback option can also hold a type parameter of the enum with these
+//! bounds, generic parameters and where clauses of the enum are carried over to all generated
+//! implementations.
+//!
+//! Any other type implementing `From<String>` and `Display` can be used by marking the fallback
+//! option with `#[strnum(display)]`, the value is then created from the owned input string and
+//! formatted with `Display`. When the field is a type parameter of the enum these bounds are added
+//! to the generated implementations. Since the value isn't a borrowed string `as_str` panics for
+//! the fallback option and it can't be combined with `str_eq`, `ord` or `hash`.
+//!
+//! Instead of `Display` the value can be formatted by a function set with
+//! `#[value(display_with = "path::to::fn")]`, taking a reference to the value and a
+//! `&mut fmt::Formatter` like `Display::fmt`. The value then only has to implement `From<String>`,
+//! which allows the fallback option to hold structured data parsed from the unknown string.
 //!
 //! If a fallback option is provided `From<String>` and `From<&'str>` is implemented for the enum,
 //! if no fallback option is provided `TryFrom` is implemented instead.
 //!
-//! Additionally, `Display` and `Into<String>` is implemented for the enum.
+//! `FromStr` is implemented in both cases, so values can be parsed with `str::parse`, with
+//! `Infallible` as error type if a fallback option is provided.
+//!
+//! A data-carrying variant marked with `#[value(prefix = "X-")]` only accepts values starting with
+//! the prefix, storing the full value including the prefix. An enum can have any number of prefixed
+//! variants next to at most one fallback option without prefix. Known values are matched first,
+//! then the prefixes in declaration order, with the fallback option taking all remaining values.
+//! A prefix that can never match because an earlier prefix covers it is a compile error. An enum
+//! with prefixed variants but no fallback option still implements `TryFrom`, rejecting values that
+//! are neither known nor prefixed.
+//!
+//! With the `regex` feature a variant can match a regex instead of a prefix, like
+//! `#[value(regex = "^v[0-9]+$")] Version(String)`, taking part in the same declaration order as
+//! prefixes. The regex is validated at compile time and compiled on first use, the crate using the
+//! derive needs to depend on `regex` and the `std` feature is required. Regexes are always matched
+//! case-sensitively, use `(?i)` in the regex to ignore case.
+//!
+//! Values for a catch-all variant can be checked by a function with
+//! `#[strnum(validate = "path::to::fn")] Other(String)`, where the function takes a `&str` and
+//! returns `bool`. Only values accepted by the function are stored in the variant, taking part in
+//! the same declaration order as prefixes. Like prefixed variants a validated variant doesn't count
+//! as fallback option, so without another fallback option `TryFrom` is implemented and rejected
+//! values return an error.
+//!
+//! Matching that can't be expressed with literals, for example because it depends on runtime
+//! configuration, can be added to any variant with `#[value(parse_with = "path::to::fn")]`. The
+//! function takes the input as `&str` and returns an `Option<Self>`, it's called in declaration
+//! order after no literal value matched and before prefixes and other patterns are tried. The
+//! const `try_from_str` only matches literal values.
+//!
+//! A variant holding a number, like `#[strnum(parse_number)] Code(u16)`, stores all values that
+//! parse as that number type, taking part in the same declaration order as prefixes. `Display`,
+//! `Into<String>` and `Into<Cow<'static, str>>` write the number back, but since a number has no
+//! borrowed string value, `as_str`, `AsRef<str>` and `Borrow<str>` panic for these values, as do
+//! the integrations that need a borrowed string except `serde`. The `str_eq`, `ord` and `hash`
+//! options can't be used on an enum with a number variant.
+//!
+//! Hierarchical values can be modelled with a nested variant holding another enum, marked with
+//! `#[strnum(nested, delimiter = ".")] Region(Region)`. Values starting with the string value of
+//! the variant followed by the delimiter are parsed into the nested enum using its `FromStr`
+//! implementation, so with `#[value = "eu"]` on the variant `"eu.west"` parses `"west"` into the
+//! nested enum. `Display` joins both values again. The delimiter defaults to `"."`, nested variants
+//! have no borrowed string value either and the same limitations as for number variants apply.
+//!
+//! The string value for a variant defaults to the variant name, it can be overwritten per variant
+//! with `#[value = "..."]` or for all variants with a container attribute like
+//! `#[strnum(rename_all = "snake_case")]`. Supported rules are `lowercase`, `UPPERCASE`,
+//! `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` and
+//! `SCREAMING-KEBAB-CASE`, a `#[value]` attribute on a variant takes precedence over the rule.
+//! `#[name = "..."]` is accepted as an alternative spelling of `#[value = "..."]`.
+//!
+//! Variants can accept additional spellings when parsing by adding one or more `#[alias = "..."]`
+//! attributes, the canonical value is still used when converting the variant back into a string.
+//! Every value and alias can only be used by a single variant, duplicates are reported as a compile
+//! error.
+//!
+//! Variants can be marked as deprecated with `#[value(deprecated)]` and old spellings can be added
+//! as `#[alias(deprecated = "...")]`, both still parse as before. The generated `is_deprecated()`
+//! returns whether a variant is deprecated, `is_deprecated_str(&str)` whether the input is a
+//! deprecated alias or a value of a deprecated variant, to log migration warnings after parsing.
+//!
+//! The empty string can be the value of a variant with `#[value = ""]`, or parsed into a variant
+//! by marking it with `#[strnum(empty)]` which keeps the variant name as its canonical value. This
+//! way empty input maps to that variant instead of the fallback option or an error, combined with
+//! `#[strnum(trim)]` this includes input consisting of only whitespace.
+//!
+//! For optional values where blank input means unset, `parse_opt` returns `None` for empty or
+//! whitespace-only input. It returns an `Option<Self>` for enums with a fallback option and a
+//! `Result<Option<Self>, _>` for enums without.
+//!
+//! Variants marked with `#[strnum(skip)]` can hold any data and are ignored when parsing and left
+//! out of `VARIANTS`. Since they have no string value, `as_str`, `Display` and `Into<String>` panic
+//! for these variants. Integrations converting the enum into a string panic for them as well.
+//!
+//! One variant can be marked with `#[strnum(default)]` to implement `Default` for the enum, for a
+//! catch-all variant the default value of the contained type is used.
+//!
+//! With `#[strnum(ascii_case_insensitive)]` on the enum, values are matched ignoring ASCII case,
+//! without the overhead of full Unicode case folding. Values that only differ in case are reported
+//! as a compile error in that case. Known values are canonicalized, parsing `"AMSTERDAM"` results
+//! in `Cities::Amsterdam` which is displayed as `"Amsterdam"` again. To preserve the original
+//! casing instead, `#[strnum(preserve_case)]` only parses values with the canonical casing into
+//! their variant and stores other spellings in the fallback option, prefixes are still matched
+//! ignoring case.
+//!
+//! `#[strnum(trim)]` strips leading and trailing whitespace from the input before matching, the
+//! fallback option stores the trimmed value. The const `try_from_str` doesn't trim its input.
+//! Similarly `#[strnum(percent_decode)]` decodes `%XX` escapes before matching, so `"New%20York"`
+//! is parsed like `"New York"` and the fallback option stores the decoded value. Invalid escapes
+//! are kept as is. This requires the `alloc` feature and can't be combined with a borrowed
+//! fallback option.
+//!
+//! Other normalization like lowercasing, stripping a byte order mark or mapping legacy spellings
+//! can be applied with `#[strnum(normalize = "path::to::fn")]`, calling a function taking the
+//! input as `&str` and returning a `Cow<str>` after decoding and trimming. The fallback option
+//! stores the normalized value, the same restrictions as for `percent_decode` apply.
+//!
+//! Values stored in the fallback option or a pattern variant convert back into exactly the parsed
+//! input, so `String::from(Enum::from(s)) == s` holds for them, also when matching ignores case.
+//! Only `trim`, `percent_decode` and `normalize` change the stored value, with
+//! `#[strnum(store_original)]` these options are only used for matching and the original input
+//! is stored instead, making the conversion lossless for proxying unknown values. Known values are
+//! always converted back into their canonical string value. `strnum::roundtrip_tests!(Enum)`
+//! generates tests checking this for every known value and alias of an enum.
+//!
+//! Values are parsed with a `match` on the input by default. For enums with a large number of
+//! values `#[strnum(lookup = "phf")]` looks up the input in a perfect hash map generated at compile
+//! time instead, this requires the crate using the derive to depend on `phf` with the `macros`
+//! feature. The `phf` lookup can't be combined with `ascii_case_insensitive`.
+//! `#[strnum(lookup = "length")]` first matches on the length of the input and then only compares
+//! the values with that length, which cuts down the number of string comparisons without requiring
+//! an extra dependency.
+//!
+//! Variants can be described with `#[strnum(message = "...")]`, otherwise the first paragraph of
+//! their doc comment is used. If any variant has a description a `description()` method returning
+//! it is generated, returning an empty string for variants without description.
+//!
+//! Variants can be grouped with `#[strnum(category = "4xx")]`, which generates a `category()`
+//! method returning the category of a variant or an empty string, a `CATEGORIES` constant listing
+//! the used categories and `iter_category(category)` iterating over the known variants in a
+//! category.
+//!
+//! Constant data can be attached to variants with
+//! `#[strnum(props(code = 404, retryable = false))]`, each property generates an accessor method
+//! like `fn code(&self) -> i32` returning the value for the variant. Every variant, including the
+//! catch-all and skipped variants, has to set every property. The return type is inferred from the
+//! literal, unsuffixed integers and floats are `i32` and `f64`, or can be declared on the enum with
+//! `#[strnum(props(code = "u16"))]`.
+//!
+//! Localized string values can be set per locale with `#[value(en = "Monday", de = "Montag")]`.
+//! `to_string_locale(locale)` returns the value for a locale, falling back to the default string
+//! value, and `from_str_locale(value, locale)` parses the localized values of a locale. Localized
+//! values aren't accepted by the other conversions. Locales are language tags with the subtags
+//! separated by underscores, like `en`, `pt_br` or `zh_hant`.
+//!
+//! `#[value(short = "NY", long = "New York")]` sets both an abbreviated and a full string value,
+//! both are parsed into the variant. The long value is used as the default string value and
+//! returned by `as_long()`, `as_short()` returns the short value.
+//!
+//! `#[strnum(abbrev)]` adds `from_abbrev()`, which also accepts any unambiguous prefix of a known
+//! value like `"Ams"` for `"Amsterdam"`. The generated `<Enum>AbbrevError` tells apart input that
+//! doesn't match any value from input that is a prefix of the values of multiple variants.
+//!
+//! With the `std` feature, `#[strnum(from_env)]` adds `from_env(variable)` reading and parsing an
+//! environment variable. The generated `<Enum>EnvError` holds the name of the variable and
+//! describes whether it's missing, not unicode or holds an unknown value, listing the known values.
+//!
+//! For C FFI, `#[strnum(ffi)]` adds `to_c_string()` and parsing from `&CStr`, invalid UTF-8 is
+//! replaced before parsing. Known values containing a nul byte are rejected at compile time, for
+//! enums with a fallback option `to_c_string()` returns an error if the value contains a nul byte
+//! and `&CStr` is converted with `From` instead of `TryFrom`. This requires the `alloc` feature.
+//!
+//! `#[strnum(debug)]` implements `Debug` using the string value, formatting
+//! `Cities::NewYork` as `Cities("New York")`, so it can't be derived as well. Skipped variants
+//! are formatted as their path. The values of number, nested and display variants are escaped like
+//! the other values, which requires the `alloc` feature.
+//!
+//! With `#[strnum(doc_values)]` the documentation of `VARIANTS` lists all accepted strings,
+//! including aliases and patterns, so the documented values can't drift from the code. A derive
+//! can't change the documentation of the enum itself, but the list is shown on the page of the
+//! enum with the other associated items.
+//!
+//! Unknown options in `#[strnum(...)]` and malformed `#[value]`, `#[name]` or `#[alias]`
+//! attributes are rejected with a compile error instead of being ignored.
+//!
+//! The error returned by `TryFrom` and `FromStr` is a generated `<Enum>ParseError` type by default,
+//! it implements `std::error::Error`, holds the unmatched input and lists the expected values.
+//! A different error type can be set with `#[strnum(error = "MyError")]`. The error is created
+//! with `From<String>`, or by calling a function taking the input as `&str` set with
+//! `#[strnum(error_fn = "MyError::unknown")]`.
+//!
+//! Enums with a fallback option also get `try_from_known(&str)`, which parses like `From` but
+//! returns the same error instead of storing unrecognized input in the fallback option. Values
+//! matching a prefix or other pattern variant are still accepted.
+//!
+//! Enums without fallback option that have an explicit variant for unknown values can mark that
+//! unit variant with `#[strnum(default_on_error)]`. Unknown input is parsed into that variant, so
+//! like for enums with a fallback option `From` is implemented instead of `TryFrom`, with
+//! `try_from_known` still available for strict parsing. The variant keeps its own string value.
+//!
+//! Enums without fallback option also get a `const fn try_from_str(&str) -> Option<Self>` so known
+//! values can be parsed in const contexts. The `strnum_const!` macro uses it to resolve a string
+//! constant to its variant at compile time, failing to compile for unknown values.
+//! These enums can also be converted into a `&'static str` with `From`, and with
+//! `const fn into_static_str` when the enum has no skipped variants.
+//!
+//! For enums where every variant has an explicit discriminant, `#[strnum(repr = "u16")]` also
+//! converts between the enum and the discriminant of the given integer type with `From` and
+//! `TryFrom`. The error of `TryFrom` is the unknown integer. This can't be combined with catch-all
+//! variants or other variants with fields.
+//!
+//! Additionally, `Display`, `Into<String>` and `Into<Cow<'static, str>>` are implemented for the
+//! enum, the latter only allocating for the fallback value. Like for `str`, `Display` honors the
+//! width, fill, alignment and precision of the formatter, so `format!("{:>12}", city)` pads the
+//! value. Number and nested variants are only padded with the `alloc` feature. An `as_str` method
+//! and `AsRef<str>` implementation are provided to get the string value without allocating,
+//! `as_bytes` returns it as bytes. Byte slices are parsed with `TryFrom<&[u8]>` after validating
+//! that they are UTF-8, for enums with a fallback option the error is the `Utf8Error`. Enums with
+//! an owned fallback option also get `from_utf8_lossy()`, storing the lossily converted input in
+//! the fallback option if it isn't valid UTF-8. All variants except the catch-all variant are
+//! listed in the `VARIANTS` associated constant and can be iterated over with `iter()`, the number
+//! of these variants is available as `COUNT`. The index of a variant in `VARIANTS` is returned by
+//! `ordinal()` as an `Option`, which is `None` for the catch-all and skipped variants.
+//! `from_ordinal()` converts the index back into the variant. Enums with a catch-all variant also
+//! get `is_known()` and `is_other()` to check whether a value was recognized, skipped variants are
+//! neither known nor other. `known_or()` and `known_or_else()` replace the catch-all variants with
+//! a default value.
+//!
+//! `#[strnum(known_enum = "ValuesKnown")]` generates a companion enum with only the known variants,
+//! `to_known()` returns the matching companion variant or `None` for the catch-all and skipped
+//! variants. Matching on the companion enum is exhaustive without handling unrecognized values.
+//! The companion enum converts into the enum with `From`, and back with `TryFrom` which returns the
+//! original value as error for the catch-all and skipped variants.
+//!
+//! With `#[strnum(str_eq)]` the enum can be compared with `str`, `&str` and `String` in both
+//! directions using the string value. This is opt-in since the extra `PartialEq` implementations
+//! break type inference for comparisons like `value == "One".into()`.
+//!
+//! `#[strnum(ord)]` implements `PartialOrd` and `Ord` by comparing the string values, so sorting
+//! follows the string order instead of the declaration order. The enum still needs to implement
+//! `Eq` itself. Similarly `#[strnum(hash)]` implements `Hash` by hashing the string value.
+//!
+//! For enums without catch-all or skipped variants, `#[strnum(set)]` generates a `<Enum>Set` type
+//! storing a set of values as a bitmask, with `insert`, `remove`, `contains` and `iter`. The set is
+//! formatted and parsed as a comma separated list like `"One,Three"`, the empty string being the
+//! empty set. Sets are limited to enums with at most 64 variants, and since the list couldn't be
+//! parsed back the values and aliases can't be empty or contain a comma:
+//!
+//! ```compile_fail
+//! use strnum::StrNum;
+//!
+//! #[derive(StrNum)]
+//! #[strnum(set)]
+//! enum Values {
+//!     #[value = "one,two"]
+//!     OneTwo,
+//!     Three,
+//! }
+//! ```
+//!
+//! `Borrow<str>` is implemented too, to look up enums in a `HashMap` or `BTreeMap` by `&str` the
+//! `Hash`, `Eq` and `Ord` implementations of the enum have to be consistent with the string value,
+//! `#[strnum(ord)]` and `#[strnum(hash)]` provide consistent `Ord` and `Hash` implementations.
+//!
+//! Individual implementations can be left out to write them by hand or get them from another
+//! derive. `#[strnum(skip_display)]` leaves out `Display`, `#[strnum(skip_into_string)]` the
+//! conversions into `String` and `Cow<'static, str>` and `#[strnum(skip_from_string)]` the
+//! `From` or `TryFrom` conversions from `String` and `&str` together with `FromStr`. The other
+//! generated methods and integrations still use these traits, so the replacements need to have
+//! the same signatures.
+//!
+//! ## Newtypes
+//!
+//! The derive also works on tuple structs wrapping a single owned string like `String` or
+//! `Box<str>`, for open-ended values that are validated instead of being one of a fixed set. The
+//! accepted values can be restricted with one or more `#[value = "..."]` attributes listing the
+//! allowed values, a `#[value(prefix = "...")]` or `#[value(regex = "...")]` pattern and a
+//! `#[strnum(validate = "...")]` function, all of which have to accept the input.
+//! `#[strnum(trim)]` and `#[strnum(ascii_case_insensitive)]` work the same as for enums. The
+//! input is stored as given apart from trimming, with `trim` the surrounding whitespace isn't
+//! preserved, while values matched case-insensitively keep their original casing.
+//!
+//! Unrestricted newtypes implement `From<&str>`, `From<String>` and `FromStr`. Restricted newtypes
+//! implement `TryFrom` and `FromStr` instead, returning a generated `<Struct>Error` holding the
+//! rejected input, and get an `is_valid(&str)` function. Both implement `Display`, `AsRef<str>`,
+//! `Into<String>`, `as_str()` and `into_inner()`. The other enum options and the integrations
+//! aren't available for newtypes.
+//!
+//! ```
+//! use strnum::StrNum;
+//! use std::convert::TryFrom;
+//!
+//! #[derive(StrNum, Debug, PartialEq)]
+//! #[value(prefix = "x-")]
+//! struct Extension(String);
+//!
+//! assert_eq!("x-custom", Extension::try_from("x-custom").unwrap().as_str());
+//! assert!(Extension::try_from("custom").is_err());
+//! ```
+//!
+//! ## Integrations
+//!
+//! Implementations for traits from other crates can be enabled with cargo features, the crate
+//! using the derive needs to depend on the integrated crate itself.
+//!
+//! - `serde`: `Serialize` and `Deserialize` using the string value. Unknown values are deserialized
+//!   into the fallback option, or rejected listing the expected values if there is none.
+//!   Borrowed fallback options borrow from the deserializer.
+//!   For compact binary formats `#[strnum(serde_repr = "index")]` represents the enum as a serde
+//!   enum instead, known values are unit variants encoded by their ordinal and the catch-all
+//!   variants share a newtype variant holding the string value. Self-describing formats like JSON
+//!   use the string value as variant name, `{"Other":"value"}` for the catch-all variants.
+//! - `schemars`: `JsonSchema` as a string schema, listing the known values if there is no fallback
+//!   option.
+//! - `clap`: `ValueEnum` so the enum can be used as argument type, listing the known values and
+//!   aliases in the help output. Since `ValueEnum` requires it, the enum needs to implement
+//!   `Clone`. Because clap only accepts the listed values for `value_enum` arguments, enums with a
+//!   fallback option also get a `<Enum>ValueParser` that accepts any other value. It's used by
+//!   default for arguments of the enum type, as long as `value_enum` isn't set on the argument.
+//!
+//! - `sqlx`: `Type`, `Encode` and `Decode` for any database, stored as text. Unknown values in the
+//!   database are decoded into the fallback option, or fail to decode if there is none.
+//! - `diesel`: `ToSql<Text, _>` and `FromSql<Text, _>` for any backend, unknown values are handled
+//!   the same way as for `sqlx`. Add `#[derive(AsExpression, FromSqlRow)]` and
+//!   `#[diesel(sql_type = Text)]` to the enum to use it in models.
+//! - `postgres-types`: `ToSql` and `FromSql` for use with `tokio-postgres` and `postgres`,
+//!   accepting both text columns and Postgres enum types. Unknown labels are decoded into the
+//!   fallback option, borrowed fallback options borrow from the row.
+//! - `rusqlite`: `ToSql` and `FromSql` storing the enum as text, unknown values are handled the
+//!   same way as for `sqlx`.
+//! - `sea-orm`: `Into<Value>`, `TryGetable`, `ValueType` and `Nullable` so the enum can be used as
+//!   column type in entities, stored as a string.
+//! - `async-graphql`: strict enums are exposed as a GraphQL enum named after the enum, with the
+//!   string values as enum values, variant descriptions and deprecations. Since GraphQL enum values
+//!   have to be valid names while the string values can be anything, enums with a fallback option,
+//!   skipped variants or values that aren't valid names are exposed as a string scalar instead.
+//!   Known values are parsed into their variant and output with their canonical value, unknown
+//!   values are stored in the fallback option or rejected if there is none. Only available for
+//!   enums without generics.
+//! - `juniper`: the same GraphQL enum or string scalar as for `async-graphql`, for use in juniper
+//!   schemas.
+//! - `utoipa`: `ToSchema` describing a string, listing the known values as enum values if there is
+//!   no fallback option or in the description of the schema otherwise.
+//! - `pyo3`: `FromPyObject` accepting python strings and `IntoPy<PyObject>`/`ToPyObject` converting
+//!   into one. Unknown values raise a `ValueError` for enums without fallback option.
+//! - `wasm-bindgen`: `Into<JsValue>` converting into a JS string. Enums with an owned fallback
+//!   option implement `From<JsValue>`, non-string values are stored as their debug representation.
+//!   Enums without fallback option implement `TryFrom<JsValue>`, returning the original value for
+//!   non-string values and an error message for unknown values.
+//! - `arbitrary`: `Arbitrary` picking one of the known variants, enums with a fallback option
+//!   also produce arbitrary strings for roughly a quarter of the values.
+//! - `rand`: `Distribution<Enum>` for `Standard`, picking one of the known variants uniformly so
+//!   the enum can be sampled with `rng.gen()`. Sampling panics if the enum has no known variants.
+//! - `quickcheck`: `Arbitrary` picking one of the known variants or, for enums with a fallback
+//!   option, an arbitrary string. Values shrink towards the variants declared before them. Since
+//!   quickcheck requires it, the enum has to implement `Clone` and can't have generics.
+//! - `miniserde`: `Serialize` and `Deserialize` using the string value, unknown values are
+//!   deserialized into the fallback option or rejected if there is none. Enums with a borrowed
+//!   `&str` fallback option only implement `Serialize`.
+//! - `http`: conversions between the enum and `HeaderValue`. Known values are converted with
+//!   `HeaderValue::from_static` without allocating, enums with a fallback option implement
+//!   `TryFrom` instead of `From` since the fallback value might not be a valid header value.
+//!   Header values are parsed with `TryFrom<&HeaderValue>`, or `From` for enums with an owned
+//!   fallback option. The string values of the known variants have to be visible ASCII.
+//! - `dialoguer`: `select_items()` listing the known values and `from_selection(index)` to build
+//!   a `Select` prompt from the enum, and `select(prompt)` showing the prompt directly. The
+//!   catch-all variant can't be selected.
+//!
+//! ## `no_std`
+//!
+//! The generated code only uses `core`, except for the conversions from and into `String` which
+//! require the `alloc` feature. Disable the default `std` feature to use the derive in a `no_std`
+//! crate, with only the `alloc` feature enabled the generated code refers to the `alloc` crate
+//! so `extern crate alloc;` is required. Catch-all variants can't be used without `alloc`.
+//!
+//! ## Re-exports
+//!
+//! All paths in the generated code are fully qualified, including items from the prelude like
+//! `Result` and `Option`, so the derive isn't affected by type aliases or other items with the same
+//! name in scope. The generated code doesn't refer to the `strnum` crate itself, so re-exporting
+//! the derive through a facade crate needs no configuration.
+//!
+//! The integrations do refer to their crates, as `::serde`, `::sqlx` and so on. When a crate is
+//! only available through a re-export, its path can be set on the enum with the name of the crate
+//! as key, like serde's `#[serde(crate = "...")]`:
+//!
+//! ```ignore
+//! #[derive(StrNum)]
+//! #[strnum(serde = "facade::serde", sqlx = "facade::sqlx")]
+//! enum Values {
+//!     One,
+//!     Two,
+//! }
+//! ```
+//!
+//! The keys are `arbitrary`, `async_graphql`, `clap`, `dialoguer`, `diesel`, `http`, `juniper`,
+//! `miniserde`, `phf`, `postgres_types`, `pyo3`, `quickcheck`, `rand`, `regex`, `rusqlite`,
+//! `schemars`, `sea_orm`, `serde`, `sqlx`, `utoipa` and `wasm_bindgen`, newtype structs only accept
+//! `regex`. Macros of these crates invoked by the generated code, like the async-graphql `Scalar`
+//! attribute, still resolve the paths of their own crate.
 //!
 //! ## Examples
 //!
@@ -28,6 +431,7 @@
 //! fn main() {
 //!     let first = Cities::from("Amsterdam");
 //!     assert_eq!(Cities::Amsterdam, first);
+//!     assert_eq!("Amsterdam", first.as_str());
 //!     assert_eq!("Amsterdam", String::from(first));
 //!
 //!     let second = Cities::from("Dublin");
@@ -60,186 +464,4179 @@
 
 extern crate proc_macro;
 
-use proc_macro2::{Span, TokenStream};
-use quote::quote_spanned;
+mod case;
+mod error;
+mod integrations;
+mod newtype;
+
+use crate::case::RenameRule;
+use crate::error::{
+    abbrev_error, abbrev_error_name, env_error, env_error_name, parse_error, parse_error_name,
+};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::collections::{BTreeMap, HashMap};
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, Variant};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Expr, Fields,
+    GenericArgument, GenericParam, Generics, Ident, Lifetime, Lit, LitStr, Meta, MetaNameValue,
+    NestedMeta, Path, PathArguments, Result, Token, Type, TypePath, TypeReference, Variant,
+    Visibility,
+};
 use syn_util::get_attribute_value;
 
 /// See the [crate documentation](index.html) for details
-#[proc_macro_derive(StrNum, attributes(value))]
+///
+/// Invalid enums are reported as compile errors, like multiple catch-all variants
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// enum Values {
+///     One,
+///     First(String),
+///     Second(String),
+/// }
+/// ```
+///
+/// multiple default variants
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// enum Values {
+///     #[strnum(default)]
+///     One,
+///     #[strnum(default)]
+///     Two,
+/// }
+/// ```
+///
+/// or an unknown `rename_all` rule
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// #[strnum(rename_all = "Title Case")]
+/// enum Values {
+///     One,
+///     Two,
+/// }
+/// ```
+///
+/// Values that are used by more than one variant are rejected
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// enum Values {
+///     One,
+///     #[alias = "One"]
+///     Two,
+/// }
+/// ```
+///
+/// also when they only differ in case and the enum matches case-insensitively
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// #[strnum(ascii_case_insensitive)]
+/// enum Values {
+///     One,
+///     #[value = "ONE"]
+///     Two,
+/// }
+/// ```
+///
+/// Unknown options in the helper attributes are rejected, like a misspelled flag
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// #[strnum(ascii_case_insensitve)]
+/// enum Values {
+///     One,
+///     Two,
+/// }
+/// ```
+///
+/// a variant attribute on the enum
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// #[value = "values"]
+/// enum Values {
+///     One,
+///     Two,
+/// }
+/// ```
+///
+/// or an unknown value option
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// enum Values {
+///     One,
+///     #[value(deprecate)]
+///     Two,
+/// }
+/// ```
+///
+/// Keys in `#[value(...)]` that aren't an option have to look like a locale, so a misspelled
+/// option isn't silently treated as a localized value
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// enum Values {
+///     One,
+///     #[value(prefx = "tw")]
+///     Two,
+/// }
+/// ```
+///
+/// Newtype structs only accept allowed values, a prefix and a regex, not the options of variants
+///
+/// ```compile_fail
+/// use strnum::StrNum;
+///
+/// #[derive(StrNum)]
+/// #[value(en = "one")]
+/// struct Value(String);
+/// ```
+#[cfg_attr(
+    feature = "http",
+    doc = r#"
+With the `http` feature, string values that aren't valid header values are rejected
+
+```compile_fail
+use strnum::StrNum;
+
+#[derive(StrNum)]
+enum Values {
+    One,
+    #[value = "twée"]
+    Two,
+}
+```"#
+)]
+#[proc_macro_derive(StrNum, attributes(value, name, alias, strnum))]
 pub fn derive_strnum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: DeriveInput = parse_macro_input!(input as DeriveInput);
 
-    let expanded = derive(input.data, &input.ident, &input.attrs);
+    let expanded = derive(
+        input.data,
+        &input.ident,
+        &input.vis,
+        &input.generics,
+        &input.attrs,
+    )
+    .unwrap_or_else(|error| error.to_compile_error());
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+/// Resolve a string to a variant of an enum without fallback option at compile time
+///
+/// ```
+/// use strnum::{strnum_const, StrNum};
+///
+/// #[derive(StrNum, PartialEq, Debug)]
+/// enum SupportedCities {
+///     Amsterdam,
+///     Tokyo,
+/// }
+///
+/// const CITY: SupportedCities = strnum_const!(SupportedCities, "Tokyo");
+/// assert_eq!(SupportedCities::Tokyo, CITY);
+/// ```
+///
+/// Unknown values fail to compile
+///
+/// ```compile_fail
+/// use strnum::{strnum_const, StrNum};
+///
+/// #[derive(StrNum, PartialEq, Debug)]
+/// enum SupportedCities {
+///     Amsterdam,
+///     Tokyo,
+/// }
+///
+/// const CITY: SupportedCities = strnum_const!(SupportedCities, "Dublin");
+/// ```
+#[proc_macro]
+pub fn strnum_const(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ConstInput { path, value } = parse_macro_input!(input as ConstInput);
+    let message = format!(
+        "unknown value \"{}\" for {}",
+        value.value().escape_default(),
+        quote! { #path }.to_string().replace(' ', "")
+    );
+
+    let expanded = quote_spanned! { value.span() =>
+        {
+            const VALUE: #path = match #path::try_from_str(#value) {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => ::core::panic!(#message),
+            };
+            VALUE
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
+
+/// Generate tests checking that every known value and alias of an enum round-trips
+///
+/// The generated `<enum>_roundtrip` test module asserts that the string value of every known
+/// variant parses back into the variant and is the output of `Display`, and that every alias
+/// parses into its variant. The enum needs to implement `PartialEq` and `Debug`.
+///
+/// ```
+/// use strnum::{roundtrip_tests, StrNum};
+///
+/// #[derive(StrNum, PartialEq, Debug)]
+/// enum Cities {
+///     Amsterdam,
+///     #[value = "New York"]
+///     #[alias = "NYC"]
+///     NewYork,
+///     Other(String),
+/// }
+///
+/// roundtrip_tests!(Cities);
+/// ```
+#[proc_macro]
+pub fn roundtrip_tests(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ty = parse_macro_input!(input as Type);
+    let name = match &ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .iter()
+            .last()
+            .map(|segment| segment.ident.clone()),
+        _ => None,
+    };
+    let name = match name {
+        Some(name) => name,
+        None => {
+            return proc_macro::TokenStream::from(
+                Error::new(ty.span(), "Expected the path of an enum deriving StrNum")
+                    .to_compile_error(),
+            )
+        }
+    };
+    let module = Ident::new(
+        &format!(
+            "{}_roundtrip",
+            RenameRule::SnakeCase.apply(&name.to_string())
+        ),
+        name.span(),
+    );
+
+    let expanded = quote_spanned! { name.span() =>
+        #[cfg(test)]
+        mod #module {
+            use super::*;
+
+            #[test]
+            fn known_values() {
+                for variant in <#ty>::iter() {
+                    let value = variant.as_str();
+                    ::core::assert_eq!(
+                        ::core::option::Option::Some(&variant),
+                        <#ty as ::core::convert::TryFrom<&str>>::try_from(value).ok().as_ref(),
+                        "parsing {:?}",
+                        value
+                    );
+                    ::core::assert_eq!(
+                        value,
+                        ::std::string::ToString::to_string(&variant),
+                        "displaying {:?}",
+                        variant
+                    );
+                }
+            }
+
+            #[test]
+            fn aliases() {
+                // the derive only emits the inherent constant for enums with aliases, which takes
+                // precedence over this empty default
+                trait NoAliases {
+                    const __STRNUM_ALIASES: &'static [(usize, &'static str)] = &[];
+                }
+                impl NoAliases for #ty {}
+
+                for (ordinal, alias) in <#ty>::__STRNUM_ALIASES {
+                    ::core::assert_eq!(
+                        <#ty>::from_ordinal(*ordinal),
+                        <#ty as ::core::convert::TryFrom<&str>>::try_from(*alias).ok(),
+                        "parsing alias {:?}",
+                        alias
+                    );
+                }
+            }
+        }
+    };
 
     proc_macro::TokenStream::from(expanded)
 }
 
-fn derive(data: Data, enum_name: &Ident, _attrs: &Vec<Attribute>) -> TokenStream {
+/// The input for `strnum_const!`, the path of the enum and the value to resolve
+struct ConstInput {
+    path: Path,
+    value: LitStr,
+}
+
+impl Parse for ConstInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let value = input.parse()?;
+        Ok(ConstInput { path, value })
+    }
+}
+
+fn derive(
+    data: Data,
+    enum_name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    attrs: &Vec<Attribute>,
+) -> Result<TokenStream> {
     let span = enum_name.span();
+    if let Data::Struct(data) = data {
+        return newtype::derive(data, enum_name, vis, generics, attrs);
+    }
+    let container = Container::from_attrs(attrs)?;
 
     match data {
         Data::Enum(data) => {
-            let options: Vec<StringOption> =
-                data.variants.into_iter().map(StringOption::from).collect();
+            let (skipped, options): (Vec<StringOption>, Vec<StringOption>) = data
+                .variants
+                .into_iter()
+                .map(|variant| StringOption::new(variant, &container))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .partition(|option| option.skip);
+            check_duplicates(&options, &container)?;
+
+            // catch-all variants with a prefix or regex only take the values matching it, the first
+            // matching pattern in declaration order wins
+            let (pattern_options, mut catch_all_options): (Vec<&StringOption>, Vec<&StringOption>) =
+                options
+                    .iter()
+                    .filter(|option| option.catch_all)
+                    .partition(|option| option.pattern.is_some());
+            if let Some(option) = catch_all_options.get(1) {
+                return Err(Error::new(
+                    option.span,
+                    "Only a single catch-all variant without prefix or regex is supported",
+                ));
+            }
+            let catch_all_option = catch_all_options.pop();
+            check_prefixes(&pattern_options, &container)?;
+            if container.preserve_case && catch_all_option.is_none() {
+                return Err(Error::new(
+                    span,
+                    "preserve_case requires a catch-all variant to store the original casing",
+                ));
+            }
+            if let Some(option) = options
+                .iter()
+                .find(|option| option.value_format().is_some())
+            {
+                if container.str_eq || container.ord || container.hash {
+                    return Err(Error::new(
+                        option.span,
+                        "A number, nested or display variant can't be combined with str_eq, ord or hash since it has no borrowed string value",
+                    ));
+                }
+            }
+            let fallback_type = catch_all_option
+                .and_then(|option| option.ty.as_ref())
+                .map(FallbackType::from_type);
+
+            let mut default_options = options.iter().filter(|option| option.default);
+            let default_option = default_options.next();
+            if let Some(option) = default_options.next() {
+                return Err(Error::new(
+                    option.span,
+                    "Only a single variant can be marked as default",
+                ));
+            }
+            let mut default_on_error_options =
+                options.iter().filter(|option| option.default_on_error);
+            let default_on_error_option = default_on_error_options.next();
+            if let Some(option) = default_on_error_options.next() {
+                return Err(Error::new(
+                    option.span,
+                    "Only a single variant can be marked as default_on_error",
+                ));
+            }
+            if let (Some(option), Some(_)) = (default_on_error_option, catch_all_option) {
+                return Err(Error::new(
+                    option.span,
+                    "default_on_error can't be combined with a catch-all variant",
+                ));
+            }
 
-            let has_fallback = match options.iter().filter(|option| option.catch_all).count() {
-                0 => false,
-                1 => true,
-                _ => panic!("Only a single catch-all variant is supported"),
+            let string_type = string_path();
+            let cow_type = cow_path();
+            let owned_fallback = fallback_type.as_ref().map_or(false, FallbackType::is_owned);
+            if let (true, None, Some(option)) = (owned_fallback, &string_type, catch_all_option) {
+                return Err(Error::new(
+                    option.span,
+                    "A catch-all variant that isn't a borrowed `&str` requires the \"alloc\" feature",
+                ));
+            }
+            // a generic display catch-all gets the bounds needed to create and format it
+            let bounded_generics;
+            let generics = match (
+                catch_all_option.filter(|option| option.display),
+                &string_type,
+            ) {
+                (Some(StringOption { ty: Some(ty), .. }), Some(string_type))
+                    if generics
+                        .type_params()
+                        .any(|param| is_type_param(ty, &param.ident)) =>
+                {
+                    let mut with_bounds = generics.clone();
+                    let predicates = &mut with_bounds.make_where_clause().predicates;
+                    if catch_all_option.map_or(false, |option| option.display_with.is_some()) {
+                        predicates.push(parse_quote! { #ty: ::core::convert::From<#string_type> });
+                    } else {
+                        predicates.push(parse_quote! {
+                            #ty: ::core::convert::From<#string_type> + ::core::fmt::Display
+                        });
+                    }
+                    bounded_generics = with_bounds;
+                    &bounded_generics
+                }
+                _ => generics,
             };
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-            let match_arms: Vec<_> = options
-                .iter()
-                .map(|option| {
-                    let span = option.span;
-                    let ident = &option.ident;
-                    let string = &option.name;
-                    if option.catch_all {
-                        quote_spanned! { span =>
-                            _ => #enum_name::#ident(value.into())
+            // pattern variants are created from the same input as the catch-all variant, a
+            // borrowed `&str` can only be stored if the catch-all variant borrows too
+            let borrowed_fallback = match fallback_type {
+                Some(FallbackType::Str(_)) => true,
+                _ => false,
+            };
+            for option in &pattern_options {
+                let borrowed = option
+                    .ty
+                    .as_ref()
+                    .map_or(false, |ty| !FallbackType::from_type(ty).is_owned());
+                if borrowed && !borrowed_fallback {
+                    return Err(Error::new(
+                        option.span,
+                        "A prefix or regex variant can only hold a borrowed `&str` if the catch-all variant does too",
+                    ));
+                }
+                if fallback_type.is_none()
+                    && string_type.is_none()
+                    && option.value_format().is_none()
+                {
+                    return Err(Error::new(
+                        option.span,
+                        "A prefix or regex variant without a catch-all variant requires the \"alloc\" feature",
+                    ));
+                }
+            }
+
+            let known_options: Vec<&StringOption> =
+                options.iter().filter(|option| !option.catch_all).collect();
+            let regex_crate = crate_path(&container.crates, "regex");
+
+            // the match arm for some or all strings of an option
+            let match_arm = |option: &StringOption, strings: &[&String]| -> TokenStream {
+                let span = option.span;
+                let ident = &option.ident;
+                let strings = strings.iter();
+                if container.ascii_case_insensitive && !container.preserve_case {
+                    quote_spanned! { span =>
+                        _ if #(value.eq_ignore_ascii_case(#strings))||* => #enum_name::#ident
+                    }
+                } else {
+                    quote_spanned! { span =>
+                        #(#strings)|* => #enum_name::#ident
+                    }
+                }
+            };
+
+            // wrap `unknown` in checks for the pattern variants, storing `capture` in the first
+            // variant with a matching pattern
+            let patterns = |input: &TokenStream, capture: &TokenStream, unknown: TokenStream| {
+                pattern_options
+                    .iter()
+                    .rev()
+                    .fold(unknown, |unknown, option| {
+                        let span = option.span;
+                        match (&option.pattern, &option.ty) {
+                            (Some(Pattern::Number), Some(ty)) => {
+                                let number = option.with_field(enum_name, quote! { number });
+                                quote_spanned! { span =>
+                                    match #input.parse::<#ty>() {
+                                        ::core::result::Result::Ok(number) => #number,
+                                        ::core::result::Result::Err(_) => #unknown,
+                                    }
+                                }
+                            }
+                            (Some(Pattern::Nested(prefix)), Some(ty)) => {
+                                let matches = Pattern::Prefix(prefix.clone()).matches(
+                                    input,
+                                    container.ascii_case_insensitive,
+                                    &regex_crate,
+                                );
+                                let length = prefix.len();
+                                let nested = option.with_field(enum_name, quote! { nested });
+                                quote_spanned! { span =>
+                                    if #matches {
+                                        match #input[#length..].parse::<#ty>() {
+                                            ::core::result::Result::Ok(nested) => #nested,
+                                            ::core::result::Result::Err(_) => #unknown,
+                                        }
+                                    } else {
+                                        #unknown
+                                    }
+                                }
+                            }
+                            (Some(pattern), _) => {
+                                let matches = pattern.matches(
+                                    input,
+                                    container.ascii_case_insensitive,
+                                    &regex_crate,
+                                );
+                                let captured = option.with_field(enum_name, capture.clone());
+                                quote_spanned! { span =>
+                                    if #matches {
+                                        #captured
+                                    } else {
+                                        #unknown
+                                    }
+                                }
+                            }
+                            (None, _) => unknown,
                         }
-                    } else {
+                    })
+            };
+
+            // parse the `&str` expression `input` derived from `value`, storing `capture` in a
+            // matching pattern variant and evaluating `unknown` if no known value matches
+            let parse = |input: TokenStream, capture: TokenStream, unknown: TokenStream| {
+                let unknown = patterns(&input, &capture, unknown);
+                let unknown = options
+                    .iter()
+                    .rev()
+                    .filter_map(|option| option.parse_with.as_ref().map(|path| (option, path)))
+                    .fold(unknown, |unknown, (option, path)| {
+                        let span = option.span;
                         quote_spanned! { span =>
-                            #string => #enum_name::#ident
+                            match #path(#input) {
+                                ::core::option::Option::Some(parsed) => parsed,
+                                ::core::option::Option::None => #unknown,
+                            }
+                        }
+                    });
+                match container.lookup {
+                    Lookup::Match => {
+                        let arms = known_options
+                            .iter()
+                            .map(|option| match_arm(option, &option.strings().collect::<Vec<_>>()));
+                        quote! {
+                            match #input {
+                                #(#arms ,)*
+                                _ => #unknown
+                            }
                         }
                     }
-                })
-                .collect();
-
-            // quote! takes ownership of anything passed to it, so instead of cloning the match arms we grab 2 Iter's
-            let match_arms_1 = match_arms.iter();
-            let match_arms_2 = match_arms.iter();
-
-            let from = if has_fallback {
-                quote_spanned! { span =>
-                    impl ::std::convert::From<String> for #enum_name {
-                        fn from(value: String) -> Self {
-                            match value.as_str() {
-                                #(#match_arms_1 ,)*
+                    Lookup::Length => {
+                        // group the strings by length, keeping the declaration order within a group
+                        let mut buckets: BTreeMap<usize, Vec<(&StringOption, Vec<&String>)>> =
+                            BTreeMap::new();
+                        for &option in &known_options {
+                            for string in option.strings() {
+                                let bucket = buckets.entry(string.len()).or_default();
+                                match bucket
+                                    .iter_mut()
+                                    .find(|(existing, _)| existing.ident == option.ident)
+                                {
+                                    Some((_, strings)) => strings.push(string),
+                                    None => bucket.push((option, vec![string])),
+                                }
                             }
                         }
+                        let buckets = buckets.iter().map(|(length, bucket)| {
+                            let length = Literal::usize_unsuffixed(*length);
+                            let arms = bucket
+                                .iter()
+                                .map(|(option, strings)| match_arm(option, strings));
+                            quote! {
+                                #length => match input {
+                                    #(#arms ,)*
+                                    _ => #unknown
+                                }
+                            }
+                        });
+                        quote! {{
+                            let input: &str = #input;
+                            match input.len() {
+                                #(#buckets ,)*
+                                _ => #unknown
+                            }
+                        }}
                     }
-
-                    impl ::std::convert::From<&str> for #enum_name {
-                        fn from(value: &str) -> Self {
-                            match value {
-                                #(#match_arms_2 ,)*
+                    Lookup::Phf => {
+                        let arms = known_options.iter().enumerate().map(|(index, option)| {
+                            let span = option.span;
+                            let ident = &option.ident;
+                            let index = Literal::usize_unsuffixed(index);
+                            quote_spanned! { span =>
+                                ::core::option::Option::Some(#index) => #enum_name::#ident
+                            }
+                        });
+                        quote! {
+                            match Self::__strnum_lookup(#input) {
+                                #(#arms ,)*
+                                _ => #unknown
                             }
                         }
                     }
                 }
+            };
+
+            // strip surrounding whitespace from the input named `value`, only allocating a new
+            // owned string if there is whitespace to strip
+            let (trim_borrowed, trim_owned) = if container.trim {
+                (
+                    quote! { let value = value.trim(); },
+                    quote! {
+                        let value = if value.trim().len() == value.len() {
+                            value
+                        } else {
+                            #string_type::from(value.trim())
+                        };
+                    },
+                )
             } else {
-                quote_spanned! { span =>
-                    impl ::std::convert::TryFrom<String> for #enum_name {
-                        type Error = String;
+                (TokenStream::new(), TokenStream::new())
+            };
 
-                        fn try_from(value: String) -> Result<Self, Self::Error> {
-                            Ok(match value.as_str() {
-                                #(#match_arms_1 ,)*
-                                _ => return Err(value)
-                            })
-                        }
+            // decode percent-encoded input named `value`, only allocating if it contains a `%`
+            let (decode_borrowed, decode_owned, percent_decode) = if container.percent_decode {
+                let string_type = match (&string_type, &fallback_type) {
+                    (None, _) => {
+                        return Err(Error::new(
+                            span,
+                            "percent_decode requires the \"alloc\" feature",
+                        ))
                     }
+                    (_, Some(FallbackType::Cow(_))) | (_, Some(FallbackType::Str(_))) => {
+                        return Err(Error::new(
+                            span,
+                            "percent_decode can't be combined with a borrowed catch-all variant",
+                        ))
+                    }
+                    (Some(string_type), _) => string_type,
+                };
+                (
+                    quote! {
+                        let decoded;
+                        let value: &str = if value.contains('%') {
+                            decoded = Self::__strnum_percent_decode(value);
+                            &decoded
+                        } else {
+                            value
+                        };
+                    },
+                    quote! {
+                        let value = if value.contains('%') {
+                            Self::__strnum_percent_decode(&value)
+                        } else {
+                            value
+                        };
+                    },
+                    quote_spanned! { span =>
+                        impl #impl_generics #enum_name #ty_generics #where_clause {
+                            /// Decode `%XX` escapes, invalid escapes are kept as is
+                            #[doc(hidden)]
+                            fn __strnum_percent_decode(value: &str) -> #string_type {
+                                fn hex(byte: u8) -> ::core::option::Option<u8> {
+                                    match byte {
+                                        b'0'..=b'9' => ::core::option::Option::Some(byte - b'0'),
+                                        b'a'..=b'f' => ::core::option::Option::Some(byte - b'a' + 10),
+                                        b'A'..=b'F' => ::core::option::Option::Some(byte - b'A' + 10),
+                                        _ => ::core::option::Option::None,
+                                    }
+                                }
 
-                    impl ::std::convert::TryFrom<&str> for #enum_name {
-                        type Error = String;
+                                let input = value.as_bytes();
+                                let mut bytes = #string_type::with_capacity(input.len()).into_bytes();
+                                let mut i = 0;
+                                while i < input.len() {
+                                    if input[i] == b'%' && i + 2 < input.len() {
+                                        if let (::core::option::Option::Some(high), ::core::option::Option::Some(low)) = (hex(input[i + 1]), hex(input[i + 2])) {
+                                            bytes.push(high << 4 | low);
+                                            i += 3;
+                                            continue;
+                                        }
+                                    }
+                                    bytes.push(input[i]);
+                                    i += 1;
+                                }
+                                match #string_type::from_utf8(bytes) {
+                                    ::core::result::Result::Ok(decoded) => decoded,
+                                    ::core::result::Result::Err(err) => #string_type::from_utf8_lossy(err.as_bytes()).into_owned(),
+                                }
+                            }
+                        }
+                    },
+                )
+            } else {
+                (TokenStream::new(), TokenStream::new(), TokenStream::new())
+            };
 
-                        fn try_from(value: &str) -> Result<Self, Self::Error> {
-                            Ok(match value {
-                                #(#match_arms_2 ,)*
-                                _ => return Err(value.to_string())
-                            })
+            // apply the normalize function to the input named `value`, the owned input is only
+            // replaced if the function changed it
+            let (normalize_borrowed, normalize_owned) = match &container.normalize {
+                Some(path) => {
+                    let (string_type, cow_type) = match (&string_type, &cow_type, &fallback_type) {
+                        (_, _, Some(FallbackType::Cow(_))) | (_, _, Some(FallbackType::Str(_))) => {
+                            return Err(Error::new(
+                                span,
+                                "normalize can't be combined with a borrowed catch-all variant",
+                            ))
                         }
+                        (Some(string_type), Some(cow_type), _) => (string_type, cow_type),
+                        _ => {
+                            return Err(Error::new(
+                                span,
+                                "normalize requires the \"alloc\" feature",
+                            ))
+                        }
+                    };
+                    (
+                        quote! {
+                            let normalized: #cow_type<str> = #path(value);
+                            let value: &str = &normalized;
+                        },
+                        quote! {
+                            let normalized = match #path(&value) {
+                                #cow_type::Borrowed(normalized) if ::core::ptr::eq(normalized, value.as_str()) => ::core::option::Option::None,
+                                #cow_type::Borrowed(normalized) => ::core::option::Option::Some(#string_type::from(normalized)),
+                                #cow_type::Owned(normalized) => ::core::option::Option::Some(normalized),
+                            };
+                            let value = normalized.unwrap_or(value);
+                        },
+                    )
+                }
+                None => (TokenStream::new(), TokenStream::new()),
+            };
+
+            // the error for unknown input, for enums with a fallback option only returned when
+            // parsing known values
+            let error_name = parse_error_name(enum_name);
+            let (error_type, error_definition) = match &container.error {
+                Some(error_type) => (quote! { #error_type }, quote! {}),
+                None => {
+                    let expected: Vec<String> = options
+                        .iter()
+                        .filter(|option| !option.catch_all || option.pattern.is_some())
+                        .map(|option| match &option.pattern {
+                            Some(Pattern::Prefix(prefix)) | Some(Pattern::Nested(prefix)) => {
+                                format!("{}...", prefix)
+                            }
+                            Some(Pattern::Regex(regex)) => regex.clone(),
+                            Some(Pattern::Number) => String::from("<number>"),
+                            Some(Pattern::Validate(path)) => format!("<{}>", path_name(path)),
+                            None => option.name.clone(),
+                        })
+                        .collect();
+                    let expected: Vec<&String> = expected.iter().collect();
+                    (
+                        quote! { #error_name },
+                        parse_error(enum_name, vis, &expected, string_type.as_ref()),
+                    )
+                }
+            };
+            let (owned_error, borrowed_error) = match (&container.error, &container.error_fn) {
+                (_, Some(error_fn)) => (quote! { #error_fn(&value) }, quote! { #error_fn(value) }),
+                (Some(error_type), None) => {
+                    match &string_type {
+                        Some(string_type) => (
+                            quote! { ::core::convert::From::from(value) },
+                            quote! { ::core::convert::From::from(#string_type::from(value)) },
+                        ),
+                        None => return Err(Error::new(
+                            error_type.span(),
+                            "A custom error type without error_fn requires the \"alloc\" feature",
+                        )),
                     }
                 }
+                (None, None) => match &string_type {
+                    Some(string_type) => (
+                        quote! { #error_name::new(value) },
+                        quote! { #error_name::new(#string_type::from(value)) },
+                    ),
+                    None => (quote! {}, quote! { #error_name::new() }),
+                },
             };
 
-            let display_arms = options.iter().map(|option| {
-                let span = option.span;
-                let ident = &option.ident;
-                let string = &option.name;
-                if option.catch_all {
+            // unknown input is stored in the catch-all variant, or mapped to the default_on_error
+            // variant which doesn't need to store it
+            let lenient = match (catch_all_option, default_on_error_option) {
+                (Some(option), _) => fallback_type
+                    .as_ref()
+                    .map(|fallback_type| (option, Some(fallback_type))),
+                (None, Some(option)) => Some((option, None)),
+                (None, None) => None,
+            };
+            let from = if let Some((unknown_option, fallback_type)) = lenient {
+                let borrowed_str =
+                    fallback_type.map_or_else(|| quote! { &str }, FallbackType::borrowed_str);
+                let owned = fallback_type.map_or(string_type.is_some(), FallbackType::is_owned);
+                // the variant for unknown input, storing `value` if it's the catch-all variant
+                let unknown = |value: TokenStream| {
+                    if unknown_option.catch_all {
+                        unknown_option.with_field(enum_name, value)
+                    } else {
+                        let ident = &unknown_option.ident;
+                        quote! { #enum_name::#ident }
+                    }
+                };
+                // with store_original the input is stored as `original` before it's decoded,
+                // trimmed or normalized
+                let (keep_original, stored) = if container.store_original {
+                    (quote! { let original = value; }, quote! { original })
+                } else {
+                    (TokenStream::new(), quote! { value })
+                };
+                // a display catch-all is only required to be created from an owned string
+                let store_borrowed = if unknown_option.display {
+                    quote! { #string_type::from(#stored).into() }
+                } else {
+                    quote! { #stored.into() }
+                };
+                let parse_borrowed = parse(
+                    quote! { value },
+                    quote! { #stored.into() },
+                    unknown(store_borrowed),
+                );
+                let parse_known = parse(
+                    quote! { value },
+                    quote! { #stored.into() },
+                    quote! { return ::core::result::Result::Err(#borrowed_error) },
+                );
+
+                // a borrowed catch-all can't be created from an owned or temporary string
+                let from_owned = if owned && !container.skip_from_string && container.store_original
+                {
+                    // the owned input is kept and matched through a borrowed copy
+                    let parse_owned = parse(
+                        quote! { value },
+                        quote! { original.into() },
+                        unknown(quote! { original.into() }),
+                    );
+
+                    quote_spanned! { span =>
+                        impl #impl_generics ::core::convert::From<#string_type> for #enum_name #ty_generics #where_clause {
+                            fn from(original: #string_type) -> Self {
+                                let value: &str = &original;
+                                #decode_borrowed
+                                #trim_borrowed
+                                #normalize_borrowed
+                                #parse_owned
+                            }
+                        }
+                    }
+                } else if owned && !container.skip_from_string {
+                    let parse_owned = parse(
+                        quote! { value.as_str() },
+                        quote! { value.into() },
+                        unknown(quote! { value.into() }),
+                    );
+
                     quote_spanned! { span =>
-                        #enum_name::#ident(value) => write!(f, "{}", value)
+                        impl #impl_generics ::core::convert::From<#string_type> for #enum_name #ty_generics #where_clause {
+                            fn from(value: #string_type) -> Self {
+                                #decode_owned
+                                #trim_owned
+                                #normalize_owned
+                                #parse_owned
+                            }
+                        }
                     }
                 } else {
+                    TokenStream::new()
+                };
+                let from_str = if (owned || fallback_type.is_none()) && !container.skip_from_string
+                {
+                    let parse_from_str = parse(
+                        quote! { value },
+                        quote! { #string_type::from(#stored).into() },
+                        unknown(quote! { #string_type::from(#stored).into() }),
+                    );
+
                     quote_spanned! { span =>
-                        #enum_name::#ident => write!(f, #string)
+                        impl #impl_generics ::core::str::FromStr for #enum_name #ty_generics #where_clause {
+                            type Err = ::core::convert::Infallible;
+
+                            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                                #keep_original
+                                #decode_borrowed
+                                #trim_borrowed
+                                #normalize_borrowed
+                                ::core::result::Result::Ok(#parse_from_str)
+                            }
+                        }
                     }
-                }
-            });
+                } else {
+                    TokenStream::new()
+                };
 
-            let to_string_arms = options.iter().map(|option| {
-                let span = option.span;
-                let ident = &option.ident;
-                let string = &option.name;
-                if option.catch_all {
+                let borrowed_bytes = match fallback_type {
+                    Some(FallbackType::Cow(lifetime)) | Some(FallbackType::Str(lifetime)) => {
+                        quote! { &#lifetime [u8] }
+                    }
+                    _ => quote! { &[u8] },
+                };
+                // valid UTF-8 is parsed without allocating, a borrowed catch-all can't store the
+                // converted string
+                let from_utf8_lossy = if owned {
                     quote_spanned! { span =>
-                        #enum_name::#ident(value) => value
+                        impl #impl_generics #enum_name #ty_generics #where_clause {
+                            /// Parse the bytes, replacing invalid UTF-8 sequences with `U+FFFD`
+                            pub fn from_utf8_lossy(bytes: #borrowed_bytes) -> Self {
+                                match #string_type::from_utf8_lossy(bytes) {
+                                    #cow_type::Borrowed(value) => <Self as ::core::convert::From<#borrowed_str>>::from(value),
+                                    #cow_type::Owned(value) => <Self as ::core::convert::From<#string_type>>::from(value),
+                                }
+                            }
+                        }
                     }
+                } else {
+                    TokenStream::new()
+                };
+
+                let from_borrowed = if container.skip_from_string {
+                    TokenStream::new()
                 } else {
                     quote_spanned! { span =>
-                        #enum_name::#ident => #string.to_string()
+                        impl #impl_generics ::core::convert::From<#borrowed_str> for #enum_name #ty_generics #where_clause {
+                            fn from(value: #borrowed_str) -> Self {
+                                #keep_original
+                                #decode_borrowed
+                                #trim_borrowed
+                                #normalize_borrowed
+                                #parse_borrowed
+                            }
+                        }
                     }
-                }
-            });
+                };
 
-            let display = quote_spanned! { span =>
-                impl ::std::fmt::Display for #enum_name {
-                    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                        match self {
-                            #(#display_arms ,)*
+                quote_spanned! { span =>
+                    #error_definition
+
+                    #from_owned
+
+                    #from_str
+
+                    #from_utf8_lossy
+
+                    impl #impl_generics ::core::convert::TryFrom<#borrowed_bytes> for #enum_name #ty_generics #where_clause {
+                        type Error = ::core::str::Utf8Error;
+
+                        fn try_from(value: #borrowed_bytes) -> ::core::result::Result<Self, Self::Error> {
+                            ::core::result::Result::Ok(<Self as ::core::convert::From<#borrowed_str>>::from(::core::str::from_utf8(value)?))
                         }
                     }
-                }
 
-                impl ::std::convert::From<#enum_name> for String {
-                    fn from(from: #enum_name) -> String {
-                        match from {
-                            #(#to_string_arms ,)*
+                    #from_borrowed
+
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Parse the value, returning `None` for empty or whitespace-only input
+                        pub fn parse_opt(value: #borrowed_str) -> ::core::option::Option<Self> {
+                            if value.trim().is_empty() {
+                                ::core::option::Option::None
+                            } else {
+                                ::core::option::Option::Some(Self::from(value))
+                            }
+                        }
+
+                        /// Parse the value, returning an error instead of falling back to the
+                        /// catch-all or `default_on_error` variant if it isn't recognized
+                        pub fn try_from_known(value: #borrowed_str) -> ::core::result::Result<Self, #error_type> {
+                            #keep_original
+                            #decode_borrowed
+                            #trim_borrowed
+                            #normalize_borrowed
+                            ::core::result::Result::Ok(#parse_known)
                         }
                     }
                 }
-            };
+            } else {
+                let try_from_owned = string_type
+                    .as_ref()
+                    .filter(|_| !container.skip_from_string)
+                    .map(|string_type| {
+                    let parse_owned = parse(
+                        quote! { value.as_str() },
+                        quote! { value.into() },
+                        quote! { return ::core::result::Result::Err(#owned_error) },
+                    );
+                    quote_spanned! { span =>
+                        impl #impl_generics ::core::convert::TryFrom<#string_type> for #enum_name #ty_generics #where_clause {
+                            type Error = #error_type;
 
-            quote_spanned! { span =>
-                #from
+                            fn try_from(value: #string_type) -> ::core::result::Result<Self, Self::Error> {
+                                #decode_owned
+                                #trim_owned
+                                #normalize_owned
+                                ::core::result::Result::Ok(#parse_owned)
+                            }
+                        }
+                    }
+                });
+                let parse_borrowed = parse(
+                    quote! { value },
+                    quote! { #string_type::from(value).into() },
+                    quote! { return ::core::result::Result::Err(#borrowed_error) },
+                );
 
-                #display
-            }
-        }
-        _ => panic!("Can only derive StrNum for enums"),
-    }
-}
+                // the error for invalid UTF-8 is created from the lossily converted input, or the
+                // valid part of the input without "alloc"
+                let invalid_utf8 = match &string_type {
+                    Some(string_type) => quote! {
+                        let value = #string_type::from_utf8_lossy(value);
+                        let value: &str = &value;
+                    },
+                    None => quote! {
+                        let value = ::core::str::from_utf8(&value[..error.valid_up_to()]).unwrap_or("");
+                    },
+                };
 
-struct StringOption {
-    ident: Ident,
-    name: String,
-    catch_all: bool,
-    span: Span,
-}
+                let try_from_str = if container.skip_from_string {
+                    TokenStream::new()
+                } else {
+                    quote_spanned! { span =>
+                        impl #impl_generics ::core::convert::TryFrom<&str> for #enum_name #ty_generics #where_clause {
+                            type Error = #error_type;
 
-impl From<Variant> for StringOption {
-    fn from(variant: Variant) -> Self {
-        let span = variant.span();
-        let name: String = get_attribute_value(&variant.attrs, &["value"])
-            .unwrap_or_else(|| variant.ident.to_string());
-        let catch_all = match variant.fields {
-            Fields::Unit => false,
-            Fields::Named(_) => panic!("Only single unnamed enum field is supported"),
-            Fields::Unnamed(ref fields) if fields.unnamed.len() > 1 => {
-                panic!("Only a single unnamed enum field is supported")
-            }
-            Fields::Unnamed(_) => true,
-        };
+                            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                                #decode_borrowed
+                                #trim_borrowed
+                                #normalize_borrowed
+                                ::core::result::Result::Ok(#parse_borrowed)
+                            }
+                        }
 
-        StringOption {
-            ident: variant.ident,
-            name,
-            catch_all,
-            span,
+                        impl #impl_generics ::core::str::FromStr for #enum_name #ty_generics #where_clause {
+                            type Err = #error_type;
+
+                            fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                                <Self as ::core::convert::TryFrom<&str>>::try_from(value)
+                            }
+                        }
+                    }
+                };
+
+                quote_spanned! { span =>
+                    #error_definition
+
+                    #try_from_owned
+
+                    impl #impl_generics ::core::convert::TryFrom<&[u8]> for #enum_name #ty_generics #where_clause {
+                        type Error = #error_type;
+
+                        #[allow(unused_variables)]
+                        fn try_from(value: &[u8]) -> ::core::result::Result<Self, Self::Error> {
+                            match ::core::str::from_utf8(value) {
+                                ::core::result::Result::Ok(value) => <Self as ::core::convert::TryFrom<&str>>::try_from(value),
+                                ::core::result::Result::Err(error) => {
+                                    #invalid_utf8
+                                    ::core::result::Result::Err(#borrowed_error)
+                                }
+                            }
+                        }
+                    }
+
+                    #try_from_str
+
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Parse the value, returning `None` for empty or whitespace-only input
+                        pub fn parse_opt(value: &str) -> ::core::result::Result<::core::option::Option<Self>, #error_type> {
+                            if value.trim().is_empty() {
+                                ::core::result::Result::Ok(::core::option::Option::None)
+                            } else {
+                                <Self as ::core::convert::TryFrom<&str>>::try_from(value).map(::core::option::Option::Some)
+                            }
+                        }
+                    }
+                }
+            };
+
+            // skipped variants have no string value to convert into
+            let skipped_arms: Vec<TokenStream> = skipped
+                .iter()
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let message = format!("{}::{} has no string value", enum_name, ident);
+                    quote_spanned! { span =>
+                        #enum_name::#ident { .. } => ::core::panic!(#message)
+                    }
+                })
+                .collect();
+
+            // convert the value named `value` of a catch-all variant into a `String`
+            let catch_all_into_string = |option: &StringOption| match (
+                &option.ty,
+                &string_type,
+                option.value_format(),
+            ) {
+                (Some(_), Some(string_type), Some(format)) => {
+                    let value = option.format_value(quote! { &value });
+                    quote! {{
+                        let mut string = #string_type::new();
+                        ::core::fmt::Write::write_fmt(&mut string, ::core::format_args!(#format, #value)).unwrap();
+                        string
+                    }}
+                }
+                (Some(ty), Some(string_type), None) => {
+                    FallbackType::from_type(ty).into_string(string_type)
+                }
+                _ => TokenStream::new(),
+            };
+
+            let display_arms = options
+                .iter()
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let string = &option.name;
+                    let binding = option.with_field(enum_name, quote! { value });
+                    if let Some(format) = option.value_format() {
+                        let value = option.format_value(quote! { value });
+                        // the length of a formatted value is only known after writing it, so it's
+                        // only padded if it can be written into a string first
+                        match &string_type {
+                            Some(string_type) => quote_spanned! { span =>
+                                #binding => if f.width().is_some() || f.precision().is_some() {
+                                    let mut string = #string_type::new();
+                                    ::core::fmt::Write::write_fmt(&mut string, ::core::format_args!(#format, #value))?;
+                                    f.pad(&string)
+                                } else {
+                                    f.write_fmt(::core::format_args!(#format, #value))
+                                }
+                            },
+                            None => quote_spanned! { span =>
+                                #binding => f.write_fmt(::core::format_args!(#format, #value))
+                            },
+                        }
+                    } else if option.catch_all {
+                        quote_spanned! { span =>
+                            #binding => f.pad(&**value)
+                        }
+                    } else {
+                        quote_spanned! { span =>
+                            #enum_name::#ident => f.pad(#string)
+                        }
+                    }
+                })
+                .chain(skipped_arms.iter().cloned());
+
+            let to_string_arms = options
+                .iter()
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let string = &option.name;
+                    let binding = option.with_field(enum_name, quote! { value });
+                    if option.catch_all {
+                        let into_string = catch_all_into_string(option);
+                        quote_spanned! { span =>
+                            #binding => #into_string
+                        }
+                    } else {
+                        quote_spanned! { span =>
+                            #enum_name::#ident => #string_type::from(#string)
+                        }
+                    }
+                })
+                .chain(skipped_arms.iter().cloned());
+
+            let to_cow_arms = options
+                .iter()
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let string = &option.name;
+                    let binding = option.with_field(enum_name, quote! { value });
+                    if option.catch_all {
+                        let into_string = catch_all_into_string(option);
+                        quote_spanned! { span =>
+                            #binding => #cow_type::Owned(#into_string)
+                        }
+                    } else {
+                        quote_spanned! { span =>
+                            #enum_name::#ident => #cow_type::Borrowed(#string)
+                        }
+                    }
+                })
+                .chain(skipped_arms.iter().cloned());
+
+            let as_str_arms = options
+                .iter()
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let string = &option.name;
+                    let binding = option.with_field(enum_name, quote! { value });
+                    if option.value_format().is_some() {
+                        let message =
+                            format!("{}::{} has no borrowed string value", enum_name, ident);
+                        let binding = option.with_field(enum_name, quote! { _ });
+                        quote_spanned! { span =>
+                            #binding => ::core::panic!(#message)
+                        }
+                    } else if option.catch_all {
+                        quote_spanned! { span =>
+                            #binding => &**value
+                        }
+                    } else {
+                        quote_spanned! { span =>
+                            #enum_name::#ident => #string
+                        }
+                    }
+                })
+                .chain(skipped_arms.iter().cloned());
+
+            let known_variants = options
+                .iter()
+                .filter(|option| !option.catch_all)
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    quote_spanned! { span =>
+                        #enum_name::#ident
+                    }
+                });
+
+            let known_count = options.iter().filter(|option| !option.catch_all).count();
+
+            // the aliases of every known variant by ordinal for `roundtrip_tests!`, only emitted if
+            // there are any
+            let alias_entries = known_options
+                .iter()
+                .enumerate()
+                .flat_map(|(index, option)| {
+                    let index = Literal::usize_unsuffixed(index);
+                    option
+                        .aliases
+                        .iter()
+                        .map(move |alias| quote! { (#index, #alias) })
+                })
+                .collect::<Vec<_>>();
+            let aliases = if alias_entries.is_empty() {
+                TokenStream::new()
+            } else {
+                quote_spanned! { span =>
+                    #[doc(hidden)]
+                    pub const __STRNUM_ALIASES: &'static [(usize, &'static str)] = &[#(#alias_entries),*];
+                }
+            };
+            let iter_arms = options
+                .iter()
+                .filter(|option| !option.catch_all)
+                .enumerate()
+                .map(|(index, option)| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let index = Literal::usize_unsuffixed(index);
+                    quote_spanned! { span =>
+                        #index => #enum_name::#ident
+                    }
+                });
+
+            // the ordinal is the index into `VARIANTS`, catch-all and skipped variants have none
+            let unordered = options
+                .iter()
+                .filter(|option| option.catch_all)
+                .chain(skipped.iter())
+                .map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    quote_spanned! { span =>
+                        #enum_name::#ident { .. } => ::core::option::Option::None
+                    }
+                })
+                .collect::<Vec<_>>();
+            let ordinal_arms = options
+                .iter()
+                .filter(|option| !option.catch_all)
+                .enumerate()
+                .map(|(index, option)| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let index = Literal::usize_unsuffixed(index);
+                    quote_spanned! { span =>
+                        #enum_name::#ident => ::core::option::Option::Some(#index)
+                    }
+                });
+            let ordinal = quote_spanned! { span =>
+                /// The index of this variant in `VARIANTS`, `None` for the catch-all and skipped
+                /// variants
+                pub fn ordinal(&self) -> ::core::option::Option<usize> {
+                    match self {
+                        #(#ordinal_arms ,)*
+                        #(#unordered ,)*
+                    }
+                }
+            };
+
+            let known_helpers = if options.iter().any(|option| option.catch_all) {
+                let other_arms = options
+                    .iter()
+                    .filter(|option| option.catch_all)
+                    .map(|option| {
+                        let span = option.span;
+                        let ident = &option.ident;
+                        quote_spanned! { span =>
+                            #enum_name::#ident { .. } => true
+                        }
+                    });
+                quote_spanned! { span =>
+                    /// Whether this is one of the known variants listed in `VARIANTS`
+                    pub fn is_known(&self) -> bool {
+                        self.ordinal().is_some()
+                    }
+
+                    /// Whether this is a catch-all variant holding an unrecognized value
+                    #[allow(unreachable_patterns)]
+                    pub fn is_other(&self) -> bool {
+                        match self {
+                            #(#other_arms ,)*
+                            _ => false,
+                        }
+                    }
+
+                    /// Replace a catch-all variant with `default`, keeping all other variants
+                    pub fn known_or(self, default: Self) -> Self {
+                        if self.is_other() {
+                            default
+                        } else {
+                            self
+                        }
+                    }
+
+                    /// Replace a catch-all variant with the result of `default`, keeping all other
+                    /// variants
+                    pub fn known_or_else<F: ::core::ops::FnOnce() -> Self>(self, default: F) -> Self {
+                        if self.is_other() {
+                            default()
+                        } else {
+                            self
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            // the `VARIANTS` slice can only hold types that are `'static`
+            let static_type = static_type(enum_name, generics);
+            let mut static_generics = generics.clone();
+            for param in generics.type_params() {
+                let ident = &param.ident;
+                static_generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #ident: 'static });
+            }
+            let static_where_clause = &static_generics.where_clause;
+
+            let const_parse = if fallback_type.is_none() && pattern_options.is_empty() {
+                let eq = if container.ascii_case_insensitive {
+                    quote! { a[i].eq_ignore_ascii_case(&b[i]) }
+                } else {
+                    quote! { a[i] == b[i] }
+                };
+                let checks = known_options.iter().map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let strings = option
+                        .strings()
+                        .map(|string| Literal::byte_string(string.as_bytes()));
+                    quote_spanned! { span =>
+                        if #(eq(value, #strings))||* {
+                            return ::core::option::Option::Some(#enum_name::#ident);
+                        }
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Parse a known value in const contexts
+                        pub const fn try_from_str(value: &str) -> ::core::option::Option<Self> {
+                            const fn eq(a: &[u8], b: &[u8]) -> bool {
+                                if a.len() != b.len() {
+                                    return false;
+                                }
+                                let mut i = 0;
+                                while i < a.len() {
+                                    if !(#eq) {
+                                        return false;
+                                    }
+                                    i += 1;
+                                }
+                                true
+                            }
+
+                            let value = value.as_bytes();
+                            #(#checks)*
+                            ::core::option::Option::None
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            // without a fallback every value has a static string
+            let static_str = if fallback_type.is_none() && pattern_options.is_empty() {
+                let static_str_arms = options
+                    .iter()
+                    .map(|option| {
+                        let span = option.span;
+                        let ident = &option.ident;
+                        let string = &option.name;
+                        quote_spanned! { span =>
+                            #enum_name::#ident => #string
+                        }
+                    })
+                    .chain(skipped_arms.iter().cloned())
+                    .collect::<Vec<_>>();
+                // skipped variants can hold fields that can't be dropped in const contexts
+                let into_static_str = if skipped.is_empty() {
+                    quote_spanned! { span =>
+                        impl #impl_generics #enum_name #ty_generics #where_clause {
+                            /// Get the static string value of this option in const contexts
+                            pub const fn into_static_str(self) -> &'static str {
+                                match self {
+                                    #(#static_str_arms ,)*
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    TokenStream::new()
+                };
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for &'static str #where_clause {
+                        fn from(from: #enum_name #ty_generics) -> &'static str {
+                            match from {
+                                #(#static_str_arms ,)*
+                            }
+                        }
+                    }
+
+                    #into_static_str
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            // every variant has an explicit discriminant, checked when parsing the variants
+            let repr = if let Some(repr) = &container.repr {
+                let variants = options.iter().chain(skipped.iter());
+                let into_arms = variants.clone().map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let discriminant = &option.discriminant;
+                    quote_spanned! { span =>
+                        #enum_name::#ident => #discriminant
+                    }
+                });
+                let from_arms = variants.map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let discriminant = &option.discriminant;
+                    quote_spanned! { span =>
+                        value if value == #discriminant => ::core::result::Result::Ok(#enum_name::#ident)
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #repr #where_clause {
+                        fn from(from: #enum_name #ty_generics) -> #repr {
+                            match from {
+                                #(#into_arms ,)*
+                            }
+                        }
+                    }
+
+                    impl #impl_generics ::core::convert::TryFrom<#repr> for #enum_name #ty_generics #where_clause {
+                        type Error = #repr;
+
+                        fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                            match value {
+                                #(#from_arms ,)*
+                                value => ::core::result::Result::Err(value),
+                            }
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let set = if container.set {
+                if catch_all_option.is_some() || !pattern_options.is_empty() || !skipped.is_empty()
+                {
+                    return Err(Error::new(
+                        span,
+                        "A set can only be generated for enums without catch-all or skipped variants",
+                    ));
+                }
+                if !generics.params.is_empty() {
+                    return Err(Error::new(
+                        span,
+                        "A set can't be generated for generic enums",
+                    ));
+                }
+                if options.len() > 64 {
+                    return Err(Error::new(
+                        span,
+                        "A set can only be generated for enums with at most 64 variants",
+                    ));
+                }
+                // the set is formatted as a comma separated list, which has to parse back
+                if let Some((option, string)) = options
+                    .iter()
+                    .flat_map(|option| option.strings().map(move |string| (option, string)))
+                    .find(|(_, string)| string.is_empty() || string.contains(','))
+                {
+                    return Err(Error::new(
+                        option.span,
+                        format!(
+                            "\"{}\" can't be used in a set, values can't be empty or contain a comma",
+                            string
+                        ),
+                    ));
+                }
+                let set_name = Ident::new(&format!("{}Set", enum_name), span);
+                let all = Literal::u64_unsuffixed(
+                    u64::MAX.checked_shr(64 - options.len() as u32).unwrap_or(0),
+                );
+                let doc = format!("A set of [`{}`] values, stored as a bitmask", enum_name);
+                quote_spanned! { span =>
+                    #[doc = #doc]
+                    #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::default::Default, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+                    #vis struct #set_name {
+                        bits: u64,
+                    }
+
+                    impl #set_name {
+                        /// An empty set
+                        pub const fn new() -> Self {
+                            #set_name { bits: 0 }
+                        }
+
+                        /// A set containing all variants
+                        pub const fn all() -> Self {
+                            #set_name { bits: #all }
+                        }
+
+                        // sets are only generated for enums without catch-all or skipped variants,
+                        // so every value has an ordinal
+                        fn bit(value: &#enum_name) -> u64 {
+                            match value.ordinal() {
+                                ::core::option::Option::Some(ordinal) => 1u64 << ordinal,
+                                ::core::option::Option::None => 0,
+                            }
+                        }
+
+                        /// Add a value to the set, returns whether the value was newly inserted
+                        pub fn insert(&mut self, value: #enum_name) -> bool {
+                            let bit = Self::bit(&value);
+                            let inserted = self.bits & bit == 0;
+                            self.bits |= bit;
+                            inserted
+                        }
+
+                        /// Remove a value from the set, returns whether the value was present
+                        pub fn remove(&mut self, value: #enum_name) -> bool {
+                            let bit = Self::bit(&value);
+                            let removed = self.bits & bit != 0;
+                            self.bits &= !bit;
+                            removed
+                        }
+
+                        /// Whether the value is in the set
+                        pub fn contains(&self, value: &#enum_name) -> bool {
+                            self.bits & Self::bit(value) != 0
+                        }
+
+                        /// The number of values in the set
+                        pub const fn len(&self) -> usize {
+                            self.bits.count_ones() as usize
+                        }
+
+                        /// Whether the set contains no values
+                        pub const fn is_empty(&self) -> bool {
+                            self.bits == 0
+                        }
+
+                        /// Iterate over the values in the set in declaration order
+                        pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #enum_name> {
+                            let bits = self.bits;
+                            (0..#enum_name::COUNT)
+                                .filter(move |ordinal| bits & (1u64 << ordinal) != 0)
+                                .filter_map(#enum_name::from_ordinal)
+                        }
+                    }
+
+                    impl ::core::iter::FromIterator<#enum_name> for #set_name {
+                        fn from_iter<I: ::core::iter::IntoIterator<Item = #enum_name>>(iter: I) -> Self {
+                            let mut set = #set_name::new();
+                            set.extend(iter);
+                            set
+                        }
+                    }
+
+                    impl ::core::iter::Extend<#enum_name> for #set_name {
+                        fn extend<I: ::core::iter::IntoIterator<Item = #enum_name>>(&mut self, iter: I) {
+                            for value in iter {
+                                self.insert(value);
+                            }
+                        }
+                    }
+
+                    impl ::core::fmt::Debug for #set_name {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            f.debug_set()
+                                .entries(self.iter().map(|value| value.as_str()))
+                                .finish()
+                        }
+                    }
+
+                    impl ::core::fmt::Display for #set_name {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            for (index, value) in self.iter().enumerate() {
+                                if index > 0 {
+                                    f.write_str(",")?;
+                                }
+                                f.write_str(value.as_str())?;
+                            }
+                            ::core::result::Result::Ok(())
+                        }
+                    }
+
+                    impl ::core::str::FromStr for #set_name {
+                        type Err = <#enum_name as ::core::str::FromStr>::Err;
+
+                        fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                            if value.is_empty() {
+                                return ::core::result::Result::Ok(#set_name::new());
+                            }
+                            value.split(',').map(|value| value.parse()).collect()
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let known_enum = if let Some(known_name) = &container.known_enum {
+                let known_idents = known_options
+                    .iter()
+                    .map(|option| &option.ident)
+                    .collect::<Vec<_>>();
+                let doc = format!(
+                    "The known variants of [`{}`], without the catch-all and skipped variants",
+                    enum_name
+                );
+                quote_spanned! { span =>
+                    #[doc = #doc]
+                    #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+                    #vis enum #known_name {
+                        #(#known_idents ,)*
+                    }
+
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// The known variant, `None` for the catch-all and skipped variants
+                        #[allow(unreachable_patterns)]
+                        pub fn to_known(&self) -> ::core::option::Option<#known_name> {
+                            match self {
+                                #(#enum_name::#known_idents => ::core::option::Option::Some(#known_name::#known_idents) ,)*
+                                _ => ::core::option::Option::None,
+                            }
+                        }
+                    }
+
+                    impl #impl_generics ::core::convert::From<#known_name> for #enum_name #ty_generics #where_clause {
+                        fn from(from: #known_name) -> Self {
+                            match from {
+                                #(#known_name::#known_idents => #enum_name::#known_idents ,)*
+                            }
+                        }
+                    }
+
+                    impl #impl_generics ::core::convert::TryFrom<#enum_name #ty_generics> for #known_name #where_clause {
+                        type Error = #enum_name #ty_generics;
+
+                        #[allow(unreachable_patterns)]
+                        fn try_from(value: #enum_name #ty_generics) -> ::core::result::Result<Self, Self::Error> {
+                            match value {
+                                #(#enum_name::#known_idents => ::core::result::Result::Ok(#known_name::#known_idents) ,)*
+                                value => ::core::result::Result::Err(value),
+                            }
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let description = if options
+                .iter()
+                .chain(skipped.iter())
+                .any(|option| option.description.is_some())
+            {
+                let arms = options.iter().chain(skipped.iter()).map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let description = option.description.as_ref().map_or("", String::as_str);
+                    quote_spanned! { span =>
+                        #enum_name::#ident { .. } => #description
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Human-readable description of the variant, empty if it has none
+                        pub fn description(&self) -> &'static str {
+                            match self {
+                                #(#arms ,)*
+                            }
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let category = if options
+                .iter()
+                .chain(skipped.iter())
+                .any(|option| option.category.is_some())
+            {
+                let arms = options.iter().chain(skipped.iter()).map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let category = option.category.as_ref().map_or("", String::as_str);
+                    quote_spanned! { span =>
+                        #enum_name::#ident { .. } => #category
+                    }
+                });
+                let mut categories: Vec<&String> = Vec::new();
+                for category in options
+                    .iter()
+                    .chain(skipped.iter())
+                    .filter_map(|option| option.category.as_ref())
+                {
+                    if !categories.contains(&category) {
+                        categories.push(category);
+                    }
+                }
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// All categories used by the variants, in declaration order
+                        pub const CATEGORIES: &'static [&'static str] = &[#(#categories),*];
+
+                        /// The category of the variant, empty if it has none
+                        pub fn category(&self) -> &'static str {
+                            match self {
+                                #(#arms ,)*
+                            }
+                        }
+
+                        /// Iterate over the known variants in `category`
+                        pub fn iter_category(category: &str) -> impl ::core::iter::Iterator<Item = Self> + '_ {
+                            Self::iter().filter(move |variant| variant.category() == category)
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let short_long = if known_options.iter().any(|option| option.short.is_some()) {
+                let arms = known_options
+                    .iter()
+                    .filter_map(|option| option.short.as_ref().map(|short| (option, short)))
+                    .map(|(option, short)| {
+                        let span = option.span;
+                        let ident = &option.ident;
+                        quote_spanned! { span =>
+                            #enum_name::#ident => #short
+                        }
+                    });
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// The short string value, the default string value if the variant has none
+                        #[allow(unreachable_patterns)]
+                        pub fn as_short(&self) -> &str {
+                            match self {
+                                #(#arms ,)*
+                                _ => self.as_str(),
+                            }
+                        }
+
+                        /// The long string value, which is the default string value
+                        pub fn as_long(&self) -> &str {
+                            self.as_str()
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let abbrev = if container.abbrev {
+                let error_name = abbrev_error_name(enum_name);
+                let error_definition = abbrev_error(
+                    enum_name,
+                    vis,
+                    &known_options
+                        .iter()
+                        .map(|option| &option.name)
+                        .collect::<Vec<_>>(),
+                );
+                let strings = known_options
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(ordinal, option)| {
+                        option
+                            .strings()
+                            .map(move |string| quote! { (#string, #ordinal) })
+                    });
+                let eq = if container.ascii_case_insensitive {
+                    quote! { a.eq_ignore_ascii_case(b) }
+                } else {
+                    quote! { a == b }
+                };
+                quote_spanned! { span =>
+                    #error_definition
+
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Parse a known value or an unambiguous prefix of one
+                        pub fn from_abbrev(value: &str) -> ::core::result::Result<Self, #error_name> {
+                            const STRINGS: &[(&str, usize)] = &[#(#strings),*];
+                            fn eq(a: &str, b: &str) -> bool {
+                                #eq
+                            }
+
+                            if value.is_empty() {
+                                return ::core::result::Result::Err(#error_name::Unknown);
+                            }
+                            // exact matches win over longer values starting with them
+                            for &(string, ordinal) in STRINGS {
+                                if eq(string, value) {
+                                    return Self::from_ordinal(ordinal).ok_or(#error_name::Unknown);
+                                }
+                            }
+                            let mut found = ::core::option::Option::None;
+                            for &(string, ordinal) in STRINGS {
+                                match string.get(..value.len()) {
+                                    ::core::option::Option::Some(prefix) if eq(prefix, value) => match found {
+                                        ::core::option::Option::Some(found) if found != ordinal => {
+                                            return ::core::result::Result::Err(#error_name::Ambiguous)
+                                        }
+                                        _ => found = ::core::option::Option::Some(ordinal),
+                                    },
+                                    _ => {}
+                                }
+                            }
+                            found
+                                .and_then(Self::from_ordinal)
+                                .ok_or(#error_name::Unknown)
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let from_env = if container.from_env {
+                if !cfg!(feature = "std") {
+                    return Err(Error::new(span, "from_env requires the \"std\" feature"));
+                }
+                let error_name = env_error_name(enum_name);
+                let error_definition = env_error(
+                    enum_name,
+                    vis,
+                    &known_options
+                        .iter()
+                        .map(|option| &option.name)
+                        .collect::<Vec<_>>(),
+                );
+                let parse = match &fallback_type {
+                    Some(FallbackType::Str(_)) => {
+                        return Err(Error::new(
+                            span,
+                            "from_env can't be used with a borrowed `&str` catch-all variant",
+                        ))
+                    }
+                    Some(_) => quote_spanned! { span =>
+                        ::core::result::Result::Ok(<Self as ::core::convert::From<::std::string::String>>::from(value))
+                    },
+                    None => quote_spanned! { span =>
+                        match <Self as ::core::convert::TryFrom<&str>>::try_from(value.as_str()) {
+                            ::core::result::Result::Ok(parsed) => ::core::result::Result::Ok(parsed),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(#error_name::Invalid {
+                                variable: variable.into(),
+                                value,
+                            }),
+                        }
+                    },
+                };
+                quote_spanned! { span =>
+                    #error_definition
+
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Read and parse the value of an environment variable
+                        pub fn from_env(variable: &str) -> ::core::result::Result<Self, #error_name> {
+                            let value = match ::std::env::var(variable) {
+                                ::core::result::Result::Ok(value) => value,
+                                ::core::result::Result::Err(::std::env::VarError::NotPresent) => {
+                                    return ::core::result::Result::Err(#error_name::Missing {
+                                        variable: variable.into(),
+                                    })
+                                }
+                                ::core::result::Result::Err(::std::env::VarError::NotUnicode(_)) => {
+                                    return ::core::result::Result::Err(#error_name::NotUnicode {
+                                        variable: variable.into(),
+                                    })
+                                }
+                            };
+                            #parse
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let ffi = if container.ffi {
+                let (ffi_path, string_type) = match (ffi_path(), &string_type) {
+                    (Some(ffi_path), Some(string_type)) => (ffi_path, string_type),
+                    _ => return Err(Error::new(span, "ffi requires the \"alloc\" feature")),
+                };
+                for option in options.iter().filter(|option| !option.catch_all) {
+                    if let Some(string) = option.strings().find(|string| string.contains('\0')) {
+                        return Err(Error::new(
+                            option.span,
+                            format!(
+                                "\"{}\" contains a nul byte and can't be converted into a C string",
+                                string.escape_default()
+                            ),
+                        ));
+                    }
+                }
+                // number and nested variants have no borrowed string value
+                let value = if options.iter().any(|option| option.value_format().is_some()) {
+                    quote_spanned! { span =>
+                        {
+                            let mut value = #string_type::new();
+                            let _ = ::core::fmt::Write::write_fmt(&mut value, ::core::format_args!("{}", self));
+                            value
+                        }
+                    }
+                } else {
+                    quote_spanned! { span => self.as_str() }
+                };
+                let to_c_string = if fallback_type.is_some() || !pattern_options.is_empty() {
+                    quote_spanned! { span =>
+                        /// Convert the string value into a C string, failing if the value contains
+                        /// a nul byte
+                        pub fn to_c_string(&self) -> ::core::result::Result<#ffi_path::CString, #ffi_path::NulError> {
+                            #ffi_path::CString::new(#value)
+                        }
+                    }
+                } else {
+                    quote_spanned! { span =>
+                        /// Convert the string value into a C string
+                        pub fn to_c_string(&self) -> #ffi_path::CString {
+                            #ffi_path::CString::new(#value).expect("known values don't contain nul bytes")
+                        }
+                    }
+                };
+                let from_c_str = match &fallback_type {
+                    Some(FallbackType::Str(_)) => TokenStream::new(),
+                    Some(_) => quote_spanned! { span =>
+                        impl #impl_generics ::core::convert::From<&::core::ffi::CStr> for #enum_name #ty_generics #where_clause {
+                            fn from(value: &::core::ffi::CStr) -> Self {
+                                <Self as ::core::convert::From<#string_type>>::from(
+                                    value.to_string_lossy().into_owned(),
+                                )
+                            }
+                        }
+                    },
+                    None => quote_spanned! { span =>
+                        impl #impl_generics ::core::convert::TryFrom<&::core::ffi::CStr> for #enum_name #ty_generics #where_clause {
+                            type Error = <Self as ::core::str::FromStr>::Err;
+
+                            fn try_from(value: &::core::ffi::CStr) -> ::core::result::Result<Self, Self::Error> {
+                                ::core::str::FromStr::from_str(&value.to_string_lossy())
+                            }
+                        }
+                    },
+                };
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        #to_c_string
+                    }
+
+                    #from_c_str
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let debug = if container.debug {
+                let name = enum_name.to_string();
+                // formatted values are escaped the same way as the borrowed string values
+                let formatted_arms = options
+                    .iter()
+                    .filter(|option| option.value_format().is_some())
+                    .map(|option| {
+                        let span = option.span;
+                        let ident = &option.ident;
+                        let string_type = string_path().ok_or_else(|| {
+                            Error::new(
+                                span,
+                                "debug with a number, nested or display variant requires the \"alloc\" feature",
+                            )
+                        })?;
+                        Ok(quote_spanned! { span =>
+                            #enum_name::#ident { .. } => {
+                                let mut value = #string_type::new();
+                                ::core::fmt::Write::write_fmt(&mut value, ::core::format_args!("{}", self))?;
+                                ::core::write!(f, "{}({:?})", #name, value)
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // skipped variants have no string value and their fields may not implement `Debug`
+                let skipped_debug_arms = skipped.iter().map(|option| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    let path = format!("{}::{}", enum_name, ident);
+                    quote_spanned! { span =>
+                        #enum_name::#ident { .. } => f.write_str(#path)
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::fmt::Debug for #enum_name #ty_generics #where_clause {
+                        #[allow(unreachable_patterns)]
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                #(#formatted_arms ,)*
+                                #(#skipped_debug_arms ,)*
+                                _ => ::core::write!(f, "{}({:?})", #name, self.as_str()),
+                            }
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let locale = if known_options
+                .iter()
+                .any(|option| !option.locales.is_empty())
+            {
+                let entries = known_options.iter().flat_map(|option| {
+                    option
+                        .locales
+                        .iter()
+                        .map(move |(locale, value)| (*option, locale, value))
+                });
+                let to_arms = entries.clone().map(|(option, locale, value)| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    quote_spanned! { span =>
+                        (#enum_name::#ident, #locale) => #value
+                    }
+                });
+                let from_arms = entries.map(|(option, locale, value)| {
+                    let span = option.span;
+                    let ident = &option.ident;
+                    if container.ascii_case_insensitive {
+                        quote_spanned! { span =>
+                            (#locale, value) if value.eq_ignore_ascii_case(#value) => ::core::option::Option::Some(#enum_name::#ident)
+                        }
+                    } else {
+                        quote_spanned! { span =>
+                            (#locale, #value) => ::core::option::Option::Some(#enum_name::#ident)
+                        }
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// The string value for `locale`, the default string value if the variant
+                        /// isn't localized for it
+                        pub fn to_string_locale(&self, locale: &str) -> &str {
+                            match (self, locale) {
+                                #(#to_arms ,)*
+                                _ => self.as_str(),
+                            }
+                        }
+
+                        /// Parse a value localized for `locale`, `None` if it's not a known value
+                        /// for that locale
+                        pub fn from_str_locale(value: &str, locale: &str) -> ::core::option::Option<Self> {
+                            match (locale, value) {
+                                #(#from_arms ,)*
+                                _ => ::core::option::Option::None,
+                            }
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let deprecated = if options
+                .iter()
+                .any(|option| option.deprecated || !option.deprecated_aliases.is_empty())
+            {
+                let deprecated_arms =
+                    options
+                        .iter()
+                        .filter(|option| option.deprecated)
+                        .map(|option| {
+                            let span = option.span;
+                            let ident = &option.ident;
+                            quote_spanned! { span =>
+                                #enum_name::#ident { .. } => true
+                            }
+                        });
+                let strings = options.iter().flat_map(|option| {
+                    let strings: Vec<&String> = if option.deprecated && !option.catch_all {
+                        option.strings().collect()
+                    } else {
+                        option.deprecated_aliases.iter().collect()
+                    };
+                    strings
+                });
+                let matches = if container.ascii_case_insensitive && !container.preserve_case {
+                    quote! { value.eq_ignore_ascii_case(deprecated) }
+                } else {
+                    quote! { value == *deprecated }
+                };
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        /// Whether this variant is deprecated
+                        #[allow(unreachable_patterns)]
+                        pub fn is_deprecated(&self) -> bool {
+                            match self {
+                                #(#deprecated_arms ,)*
+                                _ => false,
+                            }
+                        }
+
+                        /// Whether the input is a deprecated spelling, either a deprecated alias or
+                        /// a value of a deprecated variant
+                        pub fn is_deprecated_str(value: &str) -> bool {
+                            #decode_borrowed
+                            #trim_borrowed
+                            #normalize_borrowed
+                            let deprecated: &[&str] = &[#(#strings),*];
+                            deprecated.iter().any(|deprecated| #matches)
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            // properties declared on the enum come first, followed by the ones only set on variants
+            let mut prop_names: Vec<&Ident> =
+                container.props.iter().map(|(name, _)| name).collect();
+            for (name, _) in options
+                .iter()
+                .chain(skipped.iter())
+                .flat_map(|option| option.props.iter())
+            {
+                if !prop_names.contains(&name) {
+                    prop_names.push(name);
+                }
+            }
+            let mut prop_methods = Vec::with_capacity(prop_names.len());
+            for name in prop_names {
+                let mut arms = Vec::new();
+                for option in options.iter().chain(skipped.iter()) {
+                    let ident = &option.ident;
+                    let lit = match option.props.iter().find(|(prop, _)| prop == name) {
+                        Some((_, lit)) => lit,
+                        None => {
+                            return Err(Error::new(
+                                option.span,
+                                format!("Variant `{}` is missing property `{}`", ident, name),
+                            ))
+                        }
+                    };
+                    arms.push(quote_spanned! { option.span =>
+                        #enum_name::#ident { .. } => #lit
+                    });
+                }
+                let declared = container
+                    .props
+                    .iter()
+                    .find(|(prop, _)| prop == name)
+                    .map(|(_, ty)| ty.clone());
+                let ty = match declared {
+                    Some(ty) => ty,
+                    None => options
+                        .iter()
+                        .chain(skipped.iter())
+                        .flat_map(|option| option.props.iter())
+                        .find(|(prop, _)| prop == name)
+                        .and_then(|(_, lit)| literal_type(lit))
+                        .ok_or_else(|| {
+                            Error::new(
+                                name.span(),
+                                format!(
+                                    "Unknown type for property `{0}`, declare it on the enum with `#[strnum(props({0} = \"...\"))]`",
+                                    name
+                                ),
+                            )
+                        })?,
+                };
+                let doc = format!("The `{}` property of the variant", name);
+                prop_methods.push(quote_spanned! { name.span() =>
+                    #[doc = #doc]
+                    pub fn #name(&self) -> #ty {
+                        match self {
+                            #(#arms ,)*
+                        }
+                    }
+                });
+            }
+            let props = if prop_methods.is_empty() {
+                TokenStream::new()
+            } else {
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        #(#prop_methods)*
+                    }
+                }
+            };
+
+            let lookup = if container.lookup == Lookup::Phf {
+                let phf = crate_path(&container.crates, "phf");
+                let entries = known_options
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(index, option)| {
+                        let index = Literal::usize_unsuffixed(index);
+                        option
+                            .strings()
+                            .map(move |string| quote! { #string => #index })
+                    });
+                quote_spanned! { span =>
+                    impl #impl_generics #enum_name #ty_generics #where_clause {
+                        #[doc(hidden)]
+                        fn __strnum_lookup(value: &str) -> ::core::option::Option<usize> {
+                            static LOOKUP: #phf::Map<&'static str, usize> = #phf::phf_map! {
+                                #(#entries ,)*
+                            };
+                            LOOKUP.get(value).copied()
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let mut variants_doc =
+                String::from("All known variants, excluding the catch-all variant");
+            if container.doc_values {
+                variants_doc.push_str("\n\n# Accepted values\n");
+                for option in &options {
+                    let quoted = |string: &String| format!("`\"{}\"`", string.escape_default());
+                    let line = match &option.pattern {
+                        Some(Pattern::Prefix(prefix)) => {
+                            format!("values starting with {}", quoted(prefix))
+                        }
+                        Some(Pattern::Nested(prefix)) => {
+                            format!("{} followed by a nested value", quoted(prefix))
+                        }
+                        Some(Pattern::Regex(regex)) => format!("values matching `{}`", regex),
+                        Some(Pattern::Number) => String::from("numbers"),
+                        Some(Pattern::Validate(path)) => {
+                            format!("values accepted by `{}`", path_name(path))
+                        }
+                        None if option.catch_all => String::from("any other value"),
+                        None if option.aliases.is_empty() => quoted(&option.name),
+                        None => format!(
+                            "{}, also {}",
+                            quoted(&option.name),
+                            option
+                                .aliases
+                                .iter()
+                                .map(quoted)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    };
+                    let line = if option.deprecated {
+                        format!("{} (deprecated)", line)
+                    } else {
+                        line
+                    };
+                    let line = match &option.parse_with {
+                        Some(path) => {
+                            format!("{}, or values accepted by `{}`", line, path_name(path))
+                        }
+                        None => line,
+                    };
+                    variants_doc.push_str(&format!("\n- {}", line));
+                }
+            }
+            let accessors = quote_spanned! { span =>
+                impl #impl_generics #enum_name #ty_generics #static_where_clause {
+                    #[doc = #variants_doc]
+                    pub const VARIANTS: &'static [#static_type] = &[#(#known_variants),*];
+                }
+
+                impl #impl_generics #enum_name #ty_generics #where_clause {
+                    /// The number of known variants, excluding the catch-all variant
+                    pub const COUNT: usize = #known_count;
+
+                    #aliases
+
+                    /// Iterate over all known variants, excluding the catch-all variant
+                    pub fn iter() -> impl ::core::iter::Iterator<Item = Self> {
+                        (0..Self::COUNT).filter_map(Self::from_ordinal)
+                    }
+
+                    /// The variant at `ordinal` in `VARIANTS`, `None` if it's out of range
+                    pub fn from_ordinal(ordinal: usize) -> ::core::option::Option<Self> {
+                        ::core::option::Option::Some(match ordinal {
+                            #(#iter_arms ,)*
+                            _ => return ::core::option::Option::None,
+                        })
+                    }
+
+                    #ordinal
+
+                    #known_helpers
+
+                    /// Get the string value of this option without allocating
+                    pub fn as_str(&self) -> &str {
+                        match self {
+                            #(#as_str_arms ,)*
+                        }
+                    }
+
+                    /// Get the string value of this option as bytes without allocating
+                    pub fn as_bytes(&self) -> &[u8] {
+                        self.as_str().as_bytes()
+                    }
+                }
+            };
+
+            let into_string = string_type
+                .as_ref()
+                .filter(|_| !container.skip_into_string)
+                .map(|string_type| {
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #string_type #where_clause {
+                        fn from(from: #enum_name #ty_generics) -> #string_type {
+                            match from {
+                                #(#to_string_arms ,)*
+                            }
+                        }
+                    }
+
+                    impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #cow_type<'static, str> #where_clause {
+                        fn from(from: #enum_name #ty_generics) -> #cow_type<'static, str> {
+                            match from {
+                                #(#to_cow_arms ,)*
+                            }
+                        }
+                    }
+                }
+            });
+
+            let display_impl = if container.skip_display {
+                TokenStream::new()
+            } else {
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::fmt::Display for #enum_name #ty_generics #where_clause {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                            match self {
+                                #(#display_arms ,)*
+                            }
+                        }
+                    }
+                }
+            };
+            let display = quote_spanned! { span =>
+                #display_impl
+
+                impl #impl_generics ::core::convert::AsRef<str> for #enum_name #ty_generics #where_clause {
+                    fn as_ref(&self) -> &str {
+                        self.as_str()
+                    }
+                }
+
+                impl #impl_generics ::core::borrow::Borrow<str> for #enum_name #ty_generics #where_clause {
+                    fn borrow(&self) -> &str {
+                        self.as_str()
+                    }
+                }
+
+                #into_string
+            };
+
+            let str_eq = if container.str_eq {
+                let str_generics = integrations::with_params(generics, &["'__a"], &[]);
+                let (str_impl_generics, _, _) = str_generics.split_for_impl();
+                let string_eq = string_type.as_ref().map(|string_type| {
+                    quote_spanned! { span =>
+                        impl #impl_generics ::core::cmp::PartialEq<#string_type> for #enum_name #ty_generics #where_clause {
+                            fn eq(&self, other: &#string_type) -> bool {
+                                self.as_str() == other.as_str()
+                            }
+                        }
+
+                        impl #impl_generics ::core::cmp::PartialEq<#enum_name #ty_generics> for #string_type #where_clause {
+                            fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                                self.as_str() == other.as_str()
+                            }
+                        }
+                    }
+                });
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::cmp::PartialEq<str> for #enum_name #ty_generics #where_clause {
+                        fn eq(&self, other: &str) -> bool {
+                            self.as_str() == other
+                        }
+                    }
+
+                    impl #str_impl_generics ::core::cmp::PartialEq<&'__a str> for #enum_name #ty_generics #where_clause {
+                        fn eq(&self, other: &&'__a str) -> bool {
+                            self.as_str() == *other
+                        }
+                    }
+
+                    impl #impl_generics ::core::cmp::PartialEq<#enum_name #ty_generics> for str #where_clause {
+                        fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                            self == other.as_str()
+                        }
+                    }
+
+                    impl #str_impl_generics ::core::cmp::PartialEq<#enum_name #ty_generics> for &'__a str #where_clause {
+                        fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                            *self == other.as_str()
+                        }
+                    }
+
+                    #string_eq
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let ord = if container.ord {
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::cmp::PartialOrd for #enum_name #ty_generics #where_clause {
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                        }
+                    }
+
+                    impl #impl_generics ::core::cmp::Ord for #enum_name #ty_generics #where_clause {
+                        fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                            self.as_str().cmp(other.as_str())
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let hash = if container.hash {
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::hash::Hash for #enum_name #ty_generics #where_clause {
+                        fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                            ::core::hash::Hash::hash(self.as_str(), state)
+                        }
+                    }
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            let default = default_option.map(|option| {
+                let span = option.span;
+                let ident = &option.ident;
+                let value = if option.catch_all {
+                    option.with_field(enum_name, quote! { ::core::default::Default::default() })
+                } else {
+                    quote_spanned! { span => #enum_name::#ident }
+                };
+                quote_spanned! { span =>
+                    impl #impl_generics ::core::default::Default for #enum_name #ty_generics #where_clause {
+                        fn default() -> Self {
+                            #value
+                        }
+                    }
+                }
+            });
+
+            let input = Enum {
+                name: enum_name,
+                vis,
+                generics,
+                options: &options,
+                skipped: &skipped,
+                fallback_type: fallback_type.as_ref(),
+                serde_repr: container.serde_repr,
+                crates: &container.crates,
+            };
+            let integrations = input.integrations();
+
+            Ok(quote_spanned! { span =>
+                #from
+
+                #display
+
+                #accessors
+
+                #lookup
+
+                #percent_decode
+
+                #const_parse
+
+                #static_str
+
+                #repr
+
+                #set
+
+                #known_enum
+
+                #description
+
+                #category
+
+                #locale
+
+                #deprecated
+
+                #props
+
+                #short_long
+
+                #abbrev
+
+                #from_env
+
+                #ffi
+
+                #debug
+
+                #str_eq
+
+                #ord
+
+                #hash
+
+                #default
+
+                #integrations
+            })
+        }
+        _ => Err(Error::new(
+            span,
+            "Can only derive StrNum for enums and newtype structs",
+        )),
+    }
+}
+
+/// The parsed enum, used to generate the implementations for other crates
+#[cfg_attr(
+    not(any(
+        feature = "arbitrary",
+        feature = "async-graphql",
+        feature = "clap",
+        feature = "dialoguer",
+        feature = "diesel",
+        feature = "http",
+        feature = "juniper",
+        feature = "miniserde",
+        feature = "postgres-types",
+        feature = "pyo3",
+        feature = "quickcheck",
+        feature = "rand",
+        feature = "rusqlite",
+        feature = "schemars",
+        feature = "sea-orm",
+        feature = "serde",
+        feature = "sqlx",
+        feature = "utoipa",
+        feature = "wasm-bindgen"
+    )),
+    allow(dead_code)
+)]
+struct Enum<'a> {
+    name: &'a Ident,
+    #[cfg_attr(not(feature = "clap"), allow(dead_code))]
+    vis: &'a Visibility,
+    generics: &'a Generics,
+    #[cfg_attr(
+        not(any(
+            feature = "async-graphql",
+            feature = "clap",
+            feature = "dialoguer",
+            feature = "http",
+            feature = "juniper",
+            feature = "miniserde",
+            feature = "quickcheck",
+            feature = "schemars",
+            feature = "serde",
+            feature = "sqlx",
+            feature = "utoipa"
+        )),
+        allow(dead_code)
+    )]
+    options: &'a [StringOption],
+    #[cfg_attr(
+        not(any(
+            feature = "async-graphql",
+            feature = "clap",
+            feature = "http",
+            feature = "juniper",
+            feature = "quickcheck",
+            feature = "serde",
+            feature = "sqlx"
+        )),
+        allow(dead_code)
+    )]
+    skipped: &'a [StringOption],
+    #[cfg_attr(
+        not(any(
+            feature = "arbitrary",
+            feature = "async-graphql",
+            feature = "clap",
+            feature = "diesel",
+            feature = "http",
+            feature = "juniper",
+            feature = "miniserde",
+            feature = "postgres-types",
+            feature = "pyo3",
+            feature = "quickcheck",
+            feature = "rusqlite",
+            feature = "sea-orm",
+            feature = "serde",
+            feature = "sqlx",
+            feature = "wasm-bindgen"
+        )),
+        allow(dead_code)
+    )]
+    fallback_type: Option<&'a FallbackType>,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    serde_repr: SerdeRepr,
+    /// Integration crate paths overridden with their name as key like `#[strnum(serde = "...")]`,
+    /// for any of the crates in `CRATES`
+    crates: &'a [(String, Path)],
+}
+
+impl Enum<'_> {
+    /// The path to an integration crate, `::name` unless it's overridden on the enum
+    #[cfg_attr(
+        not(any(
+            feature = "arbitrary",
+            feature = "async-graphql",
+            feature = "clap",
+            feature = "dialoguer",
+            feature = "diesel",
+            feature = "http",
+            feature = "juniper",
+            feature = "miniserde",
+            feature = "postgres-types",
+            feature = "pyo3",
+            feature = "quickcheck",
+            feature = "rand",
+            feature = "rusqlite",
+            feature = "schemars",
+            feature = "sea-orm",
+            feature = "serde",
+            feature = "sqlx",
+            feature = "utoipa",
+            feature = "wasm-bindgen"
+        )),
+        allow(dead_code)
+    )]
+    fn crate_path(&self, name: &str) -> TokenStream {
+        crate_path(self.crates, name)
+    }
+
+    /// The canonical string values of all known variants
+    #[cfg_attr(
+        not(any(
+            feature = "dialoguer",
+            feature = "schemars",
+            feature = "serde",
+            feature = "utoipa"
+        )),
+        allow(dead_code)
+    )]
+    fn known_names(&self) -> Vec<&String> {
+        self.options
+            .iter()
+            .filter(|option| !option.catch_all)
+            .map(|option| &option.name)
+            .collect()
+    }
+
+    /// Whether values other than the known values are accepted, by a catch-all or pattern variant
+    #[cfg_attr(
+        not(any(
+            feature = "async-graphql",
+            feature = "http",
+            feature = "juniper",
+            feature = "schemars",
+            feature = "serde",
+            feature = "sqlx",
+            feature = "utoipa"
+        )),
+        allow(dead_code)
+    )]
+    fn accepts_unknown(&self) -> bool {
+        self.options.iter().any(|option| option.catch_all)
+    }
+
+    /// Expression parsing `value: &str` into `Self`, returning `Err(error)` for unknown values of
+    /// enums without a fallback option
+    ///
+    /// Returns `None` if the fallback option borrows and can't be created from a temporary `&str`
+    #[cfg_attr(
+        not(any(
+            feature = "arbitrary",
+            feature = "async-graphql",
+            feature = "diesel",
+            feature = "juniper",
+            feature = "miniserde",
+            feature = "postgres-types",
+            feature = "pyo3",
+            feature = "rusqlite",
+            feature = "sea-orm",
+            feature = "sqlx",
+            feature = "wasm-bindgen"
+        )),
+        allow(dead_code)
+    )]
+    fn parse_str(&self, error: TokenStream) -> Option<TokenStream> {
+        let span = self.name.span();
+        match self.fallback_type {
+            Some(FallbackType::String) | Some(FallbackType::Other) => {
+                Some(quote_spanned! { span =>
+                    <Self as ::core::convert::From<&str>>::from(value)
+                })
+            }
+            Some(FallbackType::Cow(_)) => {
+                let string_type = string_path();
+                Some(quote_spanned! { span =>
+                    <Self as ::core::convert::From<#string_type>>::from(#string_type::from(value))
+                })
+            }
+            Some(FallbackType::Str(_)) => None,
+            None => Some(quote_spanned! { span =>
+                match <Self as ::core::convert::TryFrom<&str>>::try_from(value) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => return ::core::result::Result::Err(#error),
+                }
+            }),
+        }
+    }
+
+    /// The implementations for all enabled integrations
+    fn integrations(&self) -> TokenStream {
+        #[allow(unused_mut)]
+        let mut tokens = TokenStream::new();
+        #[cfg(feature = "serde")]
+        tokens.extend(integrations::serde::derive(self));
+        #[cfg(feature = "schemars")]
+        tokens.extend(integrations::schemars::derive(self));
+        #[cfg(feature = "clap")]
+        tokens.extend(integrations::clap::derive(self));
+        #[cfg(feature = "sqlx")]
+        tokens.extend(integrations::sqlx::derive(self));
+        #[cfg(feature = "diesel")]
+        tokens.extend(integrations::diesel::derive(self));
+        #[cfg(feature = "postgres-types")]
+        tokens.extend(integrations::postgres_types::derive(self));
+        #[cfg(feature = "rusqlite")]
+        tokens.extend(integrations::rusqlite::derive(self));
+        #[cfg(feature = "sea-orm")]
+        tokens.extend(integrations::sea_orm::derive(self));
+        #[cfg(feature = "async-graphql")]
+        tokens.extend(integrations::async_graphql::derive(self));
+        #[cfg(feature = "juniper")]
+        tokens.extend(integrations::juniper::derive(self));
+        #[cfg(feature = "utoipa")]
+        tokens.extend(integrations::utoipa::derive(self));
+        #[cfg(feature = "pyo3")]
+        tokens.extend(integrations::pyo3::derive(self));
+        #[cfg(feature = "wasm-bindgen")]
+        tokens.extend(integrations::wasm_bindgen::derive(self));
+        #[cfg(feature = "arbitrary")]
+        tokens.extend(integrations::arbitrary::derive(self));
+        #[cfg(feature = "rand")]
+        tokens.extend(integrations::rand::derive(self));
+        #[cfg(feature = "quickcheck")]
+        tokens.extend(integrations::quickcheck::derive(self));
+        #[cfg(feature = "miniserde")]
+        tokens.extend(integrations::miniserde::derive(self));
+        #[cfg(feature = "http")]
+        tokens.extend(integrations::http::derive(self));
+        #[cfg(feature = "dialoguer")]
+        tokens.extend(integrations::dialoguer::derive(self));
+        tokens
+    }
+}
+
+/// Ensure that no string is parsed into more than one variant
+///
+/// When matching case-insensitively, values that only differ in case are reported as well
+fn check_duplicates(options: &[StringOption], container: &Container) -> Result<()> {
+    let mut seen: HashMap<String, (&String, &Ident)> = HashMap::new();
+    for option in options.iter().filter(|option| !option.catch_all) {
+        for string in option.strings() {
+            let key = if container.ascii_case_insensitive {
+                string.to_ascii_lowercase()
+            } else {
+                string.clone()
+            };
+            if let Some((existing_string, existing)) = seen.insert(key, (string, &option.ident)) {
+                if existing == &option.ident {
+                    continue;
+                }
+                let ident = &option.ident;
+                let message = if existing_string == string {
+                    format!(
+                        "Duplicate value \"{}\" for variants `{}` and `{}`",
+                        string, existing, ident
+                    )
+                } else {
+                    format!(
+                        "Values \"{}\" of `{}` and \"{}\" of `{}` collide when matching case-insensitively",
+                        existing_string, existing, string, ident
+                    )
+                };
+                // span both variants
+                return Err(Error::new_spanned(quote! { #existing #ident }, message));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reject prefixes that can never match because an earlier prefix already matches all their values
+fn check_prefixes(options: &[&StringOption], container: &Container) -> Result<()> {
+    let prefixes: Vec<(&StringOption, String)> = options
+        .iter()
+        .filter_map(|option| match &option.pattern {
+            Some(Pattern::Prefix(prefix)) if container.ascii_case_insensitive => {
+                Some((*option, prefix.to_ascii_lowercase()))
+            }
+            Some(Pattern::Prefix(prefix)) => Some((*option, prefix.clone())),
+            _ => None,
+        })
+        .collect();
+    for (index, (option, prefix)) in prefixes.iter().enumerate() {
+        for (earlier, earlier_prefix) in &prefixes[..index] {
+            if prefix.starts_with(earlier_prefix.as_str()) {
+                return Err(Error::new(
+                    option.span,
+                    format!(
+                        "The prefix of {} is never matched, values starting with it already match the prefix of {}",
+                        option.ident, earlier.ident
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The enum type with all lifetime parameters replaced by `'static`
+fn static_type(enum_name: &Ident, generics: &Generics) -> TokenStream {
+    if generics.params.is_empty() {
+        return quote! { #enum_name };
+    }
+    let params = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote! { 'static },
+        GenericParam::Type(param) => {
+            let ident = &param.ident;
+            quote! { #ident }
+        }
+        GenericParam::Const(param) => {
+            let ident = &param.ident;
+            quote! { #ident }
+        }
+    });
+    quote! { #enum_name<#(#params),*> }
+}
+
+/// Path to `String` for the generated code, `None` if the "alloc" feature is disabled
+fn string_path() -> Option<TokenStream> {
+    if cfg!(feature = "std") {
+        Some(quote! { ::std::string::String })
+    } else if cfg!(feature = "alloc") {
+        Some(quote! { ::alloc::string::String })
+    } else {
+        None
+    }
+}
+
+/// Path to the `alloc::ffi` module holding `CString`, if available
+fn ffi_path() -> Option<TokenStream> {
+    if cfg!(feature = "std") {
+        Some(quote! { ::std::ffi })
+    } else if cfg!(feature = "alloc") {
+        Some(quote! { ::alloc::ffi })
+    } else {
+        None
+    }
+}
+
+/// The path as written in the source, without the spacing added by `quote`
+fn path_name(path: &Path) -> String {
+    quote!(#path).to_string().replace(' ', "")
+}
+
+/// Whether `ty` is the type parameter `param`
+fn is_type_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.is_ident(param.clone()),
+        _ => false,
+    }
+}
+
+/// Path to `Cow`, if available
+fn cow_path() -> Option<TokenStream> {
+    if cfg!(feature = "std") {
+        Some(quote! { ::std::borrow::Cow })
+    } else if cfg!(feature = "alloc") {
+        Some(quote! { ::alloc::borrow::Cow })
+    } else {
+        None
+    }
+}
+
+/// Options set on the enum itself with `#[strnum(...)]`
+struct Container {
+    rename_all: Option<RenameRule>,
+    ascii_case_insensitive: bool,
+    str_eq: bool,
+    ord: bool,
+    hash: bool,
+    trim: bool,
+    preserve_case: bool,
+    set: bool,
+    doc_values: bool,
+    abbrev: bool,
+    from_env: bool,
+    percent_decode: bool,
+    ffi: bool,
+    debug: bool,
+    skip_display: bool,
+    skip_from_string: bool,
+    skip_into_string: bool,
+    store_original: bool,
+    lookup: Lookup,
+    error: Option<Type>,
+    error_fn: Option<Path>,
+    normalize: Option<Path>,
+    repr: Option<Type>,
+    known_enum: Option<Ident>,
+    serde_repr: SerdeRepr,
+    /// Types of the variant properties from `#[strnum(props(name = "type"))]`
+    props: Vec<(Ident, Type)>,
+    /// Integration crate paths overridden with their name as key, for the crates in `CRATES`
+    crates: Vec<(String, Path)>,
+}
+
+impl Container {
+    /// Flags allowed in `#[strnum(...)]` on the enum
+    const FLAGS: &'static [&'static str] = &[
+        "ascii_case_insensitive",
+        "preserve_case",
+        "str_eq",
+        "ord",
+        "hash",
+        "trim",
+        "set",
+        "doc_values",
+        "abbrev",
+        "from_env",
+        "percent_decode",
+        "ffi",
+        "debug",
+        "skip_display",
+        "skip_from_string",
+        "skip_into_string",
+        "store_original",
+    ];
+    /// Options with a string value allowed in `#[strnum(...)]` on the enum
+    const KEYS: &'static [&'static str] = &[
+        "rename_all",
+        "error",
+        "error_fn",
+        "lookup",
+        "repr",
+        "known_enum",
+        "serde_repr",
+        "normalize",
+    ];
+
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        validate_attributes(
+            attrs,
+            Container::FLAGS,
+            &[Container::KEYS, CRATES].concat(),
+            false,
+        )?;
+        let crates = get_crate_paths(attrs)?;
+
+        let props = get_props(attrs)?
+            .into_iter()
+            .map(|(name, lit)| match lit {
+                Lit::Str(ty) => match ty.parse::<Type>() {
+                    Ok(ty) => Ok((name, ty)),
+                    Err(_) => Err(Error::new(
+                        ty.span(),
+                        format!("Invalid property type \"{}\"", ty.value()),
+                    )),
+                },
+                lit => Err(Error::new(
+                    lit.span(),
+                    "Expected the type of the property like `props(code = \"u16\")`",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let rename_all = match get_attribute_str(attrs, "strnum", "rename_all") {
+            Some(rule) => Some(RenameRule::from_name(&rule.value()).ok_or_else(|| {
+                Error::new(
+                    rule.span(),
+                    format!(
+                        "Unknown rename_all rule \"{}\", expected one of {}",
+                        rule.value(),
+                        RenameRule::NAMES.join(", ")
+                    ),
+                )
+            })?),
+            None => None,
+        };
+
+        let error = match get_attribute_str(attrs, "strnum", "error") {
+            Some(error) => Some(error.parse::<Type>().map_err(|_| {
+                Error::new(
+                    error.span(),
+                    format!("Invalid error type \"{}\"", error.value()),
+                )
+            })?),
+            None => None,
+        };
+        let error_fn = match get_attribute_str(attrs, "strnum", "error_fn") {
+            Some(path) => {
+                if error.is_none() {
+                    return Err(Error::new(
+                        path.span(),
+                        "error_fn can only be used together with a custom error type",
+                    ));
+                }
+                Some(path.parse::<Path>().map_err(|_| {
+                    Error::new(
+                        path.span(),
+                        format!("Invalid error constructor \"{}\"", path.value()),
+                    )
+                })?)
+            }
+            None => None,
+        };
+        let normalize = match get_attribute_str(attrs, "strnum", "normalize") {
+            Some(path) => Some(path.parse::<Path>().map_err(|_| {
+                Error::new(
+                    path.span(),
+                    format!("Invalid normalize function \"{}\"", path.value()),
+                )
+            })?),
+            None => None,
+        };
+
+        let repr = match get_attribute_str(attrs, "strnum", "repr") {
+            Some(repr) => Some(repr.parse::<Type>().map_err(|_| {
+                Error::new(
+                    repr.span(),
+                    format!("Invalid repr type \"{}\"", repr.value()),
+                )
+            })?),
+            None => None,
+        };
+
+        let known_enum = match get_attribute_str(attrs, "strnum", "known_enum") {
+            Some(name) => Some(name.parse::<Ident>().map_err(|_| {
+                Error::new(
+                    name.span(),
+                    format!("Invalid enum name \"{}\"", name.value()),
+                )
+            })?),
+            None => None,
+        };
+
+        let serde_repr = match get_attribute_str(attrs, "strnum", "serde_repr") {
+            Some(repr) => match repr.value().as_str() {
+                "string" => SerdeRepr::String,
+                "index" => SerdeRepr::Index,
+                _ => {
+                    return Err(Error::new(
+                        repr.span(),
+                        format!(
+                            "Unknown serde_repr \"{}\", expected \"string\" or \"index\"",
+                            repr.value()
+                        ),
+                    ))
+                }
+            },
+            None => SerdeRepr::String,
+        };
+
+        let ascii_case_insensitive = has_attribute_flag(attrs, "strnum", "ascii_case_insensitive");
+        let preserve_case = has_attribute_flag(attrs, "strnum", "preserve_case");
+        if preserve_case && !ascii_case_insensitive {
+            return Err(Error::new(
+                Span::call_site(),
+                "preserve_case can only be used together with ascii_case_insensitive",
+            ));
+        }
+        let lookup = match get_attribute_str(attrs, "strnum", "lookup") {
+            Some(lookup) => match lookup.value().as_str() {
+                "match" => Lookup::Match,
+                "length" => Lookup::Length,
+                "phf" if ascii_case_insensitive => {
+                    return Err(Error::new(
+                        lookup.span(),
+                        "The phf lookup can't be combined with ascii_case_insensitive",
+                    ))
+                }
+                "phf" => Lookup::Phf,
+                _ => {
+                    return Err(Error::new(
+                        lookup.span(),
+                        format!(
+                            "Unknown lookup \"{}\", expected \"match\", \"length\" or \"phf\"",
+                            lookup.value()
+                        ),
+                    ))
+                }
+            },
+            None => Lookup::Match,
+        };
+
+        Ok(Container {
+            rename_all,
+            ascii_case_insensitive,
+            str_eq: has_attribute_flag(attrs, "strnum", "str_eq"),
+            ord: has_attribute_flag(attrs, "strnum", "ord"),
+            hash: has_attribute_flag(attrs, "strnum", "hash"),
+            trim: has_attribute_flag(attrs, "strnum", "trim"),
+            preserve_case,
+            set: has_attribute_flag(attrs, "strnum", "set"),
+            doc_values: has_attribute_flag(attrs, "strnum", "doc_values"),
+            abbrev: has_attribute_flag(attrs, "strnum", "abbrev"),
+            from_env: has_attribute_flag(attrs, "strnum", "from_env"),
+            percent_decode: has_attribute_flag(attrs, "strnum", "percent_decode"),
+            ffi: has_attribute_flag(attrs, "strnum", "ffi"),
+            debug: has_attribute_flag(attrs, "strnum", "debug"),
+            skip_display: has_attribute_flag(attrs, "strnum", "skip_display"),
+            skip_from_string: has_attribute_flag(attrs, "strnum", "skip_from_string"),
+            skip_into_string: has_attribute_flag(attrs, "strnum", "skip_into_string"),
+            store_original: has_attribute_flag(attrs, "strnum", "store_original"),
+            lookup,
+            error,
+            error_fn,
+            normalize,
+            repr,
+            known_enum,
+            serde_repr,
+            props,
+            crates,
+        })
+    }
+}
+
+/// How the input is matched against the known values when parsing
+#[derive(Clone, Copy, PartialEq)]
+enum Lookup {
+    /// A `match` on the input string
+    Match,
+    /// A `match` on the length of the input, followed by a `match` on the strings of that length
+    Length,
+    /// A perfect hash map generated at compile time by `phf`
+    Phf,
+}
+
+/// How the enum is represented by the `serde` integration
+#[derive(Clone, Copy, PartialEq)]
+enum SerdeRepr {
+    /// The string value
+    String,
+    /// A serde enum with a unit variant per known value, the catch-all variants as newtype variant
+    /// holding the string value
+    Index,
+}
+
+struct StringOption {
+    ident: Ident,
+    name: String,
+    aliases: Vec<String>,
+    catch_all: bool,
+    /// The name of the field of a struct-style catch-all variant
+    field: Option<Ident>,
+    /// Only values matching the pattern are stored in this catch-all variant
+    pattern: Option<Pattern>,
+    default: bool,
+    skip: bool,
+    ty: Option<Type>,
+    /// The explicit discriminant of the variant
+    discriminant: Option<Expr>,
+    /// Human-readable description from `#[strnum(message = "...")]` or the doc comment
+    description: Option<String>,
+    /// Group of the variant from `#[strnum(category = "...")]`
+    category: Option<String>,
+    /// Localized string values as `(locale, value)`
+    locales: Vec<(String, String)>,
+    /// The abbreviated string value from `#[value(short = "...")]`
+    short: Option<String>,
+    /// The catch-all value is created from a `String` and formatted with `Display`
+    display: bool,
+    /// Function from `#[value(display_with = "...")]` formatting the catch-all value
+    display_with: Option<Path>,
+    /// Function from `#[value(parse_with = "...")]` parsing input the literals don't match
+    parse_with: Option<Path>,
+    /// Unknown input is parsed into this unit variant
+    default_on_error: bool,
+    /// Marked with `#[value(deprecated)]`
+    deprecated: bool,
+    /// Spellings from `#[alias(deprecated = "...")]`, also included in `aliases`
+    deprecated_aliases: Vec<String>,
+    /// Constant properties from `#[strnum(props(name = value))]`
+    props: Vec<(Ident, Lit)>,
+    span: Span,
+}
+
+impl StringOption {
+    /// Flags allowed in `#[strnum(...)]` on a variant
+    const FLAGS: &'static [&'static str] = &[
+        "default",
+        "skip",
+        "parse_number",
+        "nested",
+        "empty",
+        "display",
+        "default_on_error",
+    ];
+    /// Options with a string value allowed in `#[strnum(...)]` on a variant
+    const KEYS: &'static [&'static str] = &["delimiter", "message", "validate", "category"];
+
+    fn new(variant: Variant, container: &Container) -> Result<Self> {
+        let span = variant.span();
+        validate_attributes(
+            &variant.attrs,
+            StringOption::FLAGS,
+            StringOption::KEYS,
+            true,
+        )?;
+        if container.repr.is_some() {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(Error::new(
+                    span,
+                    "A repr can only be set on enums without catch-all or other variants with fields",
+                ));
+            }
+            if variant.discriminant.is_none() {
+                return Err(Error::new(
+                    span,
+                    "A repr requires an explicit discriminant on every variant",
+                ));
+            }
+        }
+        let description = get_attribute_str(&variant.attrs, "strnum", "message")
+            .map(|message| message.value())
+            .or_else(|| get_doc_summary(&variant.attrs));
+        let category = get_attribute_str(&variant.attrs, "strnum", "category")
+            .map(|category| category.value());
+        let props = get_props(&variant.attrs)?;
+        if has_attribute_flag(&variant.attrs, "strnum", "skip") {
+            if has_attribute_flag(&variant.attrs, "strnum", "default") {
+                return Err(Error::new(
+                    span,
+                    "Skipped variants can't be marked as default",
+                ));
+            }
+            return Ok(StringOption {
+                name: variant.ident.to_string(),
+                ident: variant.ident,
+                aliases: Vec::new(),
+                catch_all: false,
+                field: None,
+                pattern: None,
+                default: false,
+                skip: true,
+                ty: None,
+                discriminant: variant.discriminant.map(|(_, expr)| expr),
+                description,
+                category,
+                locales: Vec::new(),
+                short: None,
+                display: false,
+                display_with: None,
+                parse_with: None,
+                default_on_error: false,
+                deprecated: false,
+                deprecated_aliases: Vec::new(),
+                props,
+                span,
+            });
+        }
+        let value: Option<String> = get_attribute_value(&variant.attrs, &["value"]);
+        let renamed: Option<String> = get_attribute_value(&variant.attrs, &["name"]);
+        if value.is_some() && renamed.is_some() {
+            return Err(Error::new(
+                span,
+                "`#[value]` and `#[name]` can't be used on the same variant",
+            ));
+        }
+        let long = get_attribute_str(&variant.attrs, "value", "long").map(|lit| lit.value());
+        let short = get_attribute_str(&variant.attrs, "value", "short").map(|lit| lit.value());
+        if long.is_some() && (value.is_some() || renamed.is_some()) {
+            return Err(Error::new(
+                span,
+                "A long value can't be combined with `#[value = \"...\"]` or `#[name]`",
+            ));
+        }
+        let name = value
+            .or(renamed)
+            .or(long)
+            .unwrap_or_else(|| match container.rename_all {
+                Some(rule) => rule.apply(&variant.ident.to_string()),
+                None => variant.ident.to_string(),
+            });
+        let (field, ty) = match variant.fields {
+            Fields::Unit => (None, None),
+            Fields::Named(ref fields) if fields.named.len() != 1 => {
+                return Err(Error::new(
+                    fields.span(),
+                    "Only a single enum field is supported",
+                ))
+            }
+            Fields::Named(fields) => match fields.named.into_iter().next() {
+                Some(field) => (field.ident, Some(field.ty)),
+                None => (None, None),
+            },
+            Fields::Unnamed(ref fields) if fields.unnamed.len() > 1 => {
+                return Err(Error::new(
+                    fields.span(),
+                    "Only a single enum field is supported",
+                ))
+            }
+            Fields::Unnamed(fields) => (
+                None,
+                fields.unnamed.into_iter().next().map(|field| field.ty),
+            ),
+        };
+        let catch_all = ty.is_some();
+        let mut aliases = get_attribute_values(&variant.attrs, "alias");
+        let deprecated_aliases = get_deprecated_aliases(&variant.attrs);
+        aliases.extend(deprecated_aliases.iter().cloned());
+        if catch_all && !aliases.is_empty() {
+            return Err(Error::new(
+                span,
+                "Aliases are not supported on the catch-all variant",
+            ));
+        }
+        // the short form is parsed into this variant as an extra alias
+        if let Some(short) = &short {
+            if catch_all {
+                return Err(Error::new(
+                    span,
+                    "Short or long values are not supported on the catch-all variant",
+                ));
+            }
+            aliases.push(short.clone());
+        }
+        // the empty string is parsed into this variant as an extra alias
+        if has_attribute_flag(&variant.attrs, "strnum", "empty") {
+            if catch_all {
+                return Err(Error::new(
+                    span,
+                    "The catch-all variant can't be marked as empty",
+                ));
+            }
+            aliases.push(String::new());
+        }
+        let pattern = match (
+            get_attribute_str(&variant.attrs, "value", "prefix"),
+            get_attribute_str(&variant.attrs, "value", "regex"),
+        ) {
+            (Some(_), Some(regex)) => {
+                return Err(Error::new(
+                    regex.span(),
+                    "A variant can't have both a prefix and a regex",
+                ))
+            }
+            (Some(prefix), None) => Some(Pattern::Prefix(prefix.value())),
+            (None, Some(regex)) => Some(Pattern::regex(&regex)?),
+            (None, None) => None,
+        };
+        let pattern = if has_attribute_flag(&variant.attrs, "strnum", "parse_number") {
+            if pattern.is_some() {
+                return Err(Error::new(
+                    span,
+                    "A number variant can't have a prefix or regex",
+                ));
+            }
+            Some(Pattern::Number)
+        } else {
+            pattern
+        };
+        let pattern = if has_attribute_flag(&variant.attrs, "strnum", "nested") {
+            if pattern.is_some() {
+                return Err(Error::new(
+                    span,
+                    "A nested variant can't have a prefix, regex or parse_number",
+                ));
+            }
+            let delimiter = get_attribute_str(&variant.attrs, "strnum", "delimiter")
+                .map_or_else(|| String::from("."), |lit| lit.value());
+            Some(Pattern::Nested(format!("{}{}", name, delimiter)))
+        } else if let Some(delimiter) = get_attribute_str(&variant.attrs, "strnum", "delimiter") {
+            return Err(Error::new(
+                delimiter.span(),
+                "A delimiter can only be set on a nested variant",
+            ));
+        } else {
+            pattern
+        };
+        let pattern = match get_attribute_str(&variant.attrs, "strnum", "validate") {
+            Some(path) => {
+                if pattern.is_some() {
+                    return Err(Error::new(
+                        path.span(),
+                        "A validated variant can't have a prefix, regex, parse_number or nested",
+                    ));
+                }
+                Some(Pattern::Validate(path.parse::<Path>().map_err(|_| {
+                    Error::new(
+                        path.span(),
+                        format!("Invalid validation function \"{}\"", path.value()),
+                    )
+                })?))
+            }
+            None => pattern,
+        };
+        if !catch_all && pattern.is_some() {
+            return Err(Error::new(
+                span,
+                "A prefix, regex, parse_number, nested or validate can only be set on a catch-all variant",
+            ));
+        }
+        let default = has_attribute_flag(&variant.attrs, "strnum", "default");
+        let default_on_error = has_attribute_flag(&variant.attrs, "strnum", "default_on_error");
+        if default_on_error && catch_all {
+            return Err(Error::new(
+                span,
+                "default_on_error can only be set on a unit variant",
+            ));
+        }
+        let display_with = match get_attribute_str(&variant.attrs, "value", "display_with") {
+            Some(path) => Some(path.parse::<Path>().map_err(|_| {
+                Error::new(
+                    path.span(),
+                    format!("Invalid display function \"{}\"", path.value()),
+                )
+            })?),
+            None => None,
+        };
+        // a custom display function implies the value doesn't deref to `str`
+        let display =
+            has_attribute_flag(&variant.attrs, "strnum", "display") || display_with.is_some();
+        if display {
+            if !catch_all || pattern.is_some() {
+                return Err(Error::new(
+                    span,
+                    "display or display_with can only be set on a catch-all variant without prefix, regex, parse_number, nested or validate",
+                ));
+            }
+            if !ty
+                .as_ref()
+                .map_or(false, |ty| FallbackType::from_type(ty).is_owned())
+            {
+                return Err(Error::new(
+                    span,
+                    "A display catch-all variant can't hold a borrowed value",
+                ));
+            }
+        }
+        let parse_with = match get_attribute_str(&variant.attrs, "value", "parse_with") {
+            Some(path) => Some(path.parse::<Path>().map_err(|_| {
+                Error::new(
+                    path.span(),
+                    format!("Invalid parse function \"{}\"", path.value()),
+                )
+            })?),
+            None => None,
+        };
+        let locales = get_locales(&variant.attrs)?;
+        if catch_all && !locales.is_empty() {
+            return Err(Error::new(
+                span,
+                "Localized values are not supported on the catch-all variant",
+            ));
+        }
+
+        Ok(StringOption {
+            ident: variant.ident,
+            name,
+            aliases,
+            catch_all,
+            field,
+            pattern,
+            default,
+            skip: false,
+            ty,
+            discriminant: variant.discriminant.map(|(_, expr)| expr),
+            description,
+            category,
+            locales,
+            short,
+            display,
+            display_with,
+            parse_with,
+            default_on_error,
+            deprecated: has_attribute_flag(&variant.attrs, "value", "deprecated"),
+            deprecated_aliases,
+            props,
+            span,
+        })
+    }
+
+    /// The catch-all variant holding `value`, as expression or pattern
+    ///
+    /// Uses the tuple form `Enum::Other(value)` or the struct form `Enum::Other { field: value }`
+    fn with_field(&self, enum_name: &Ident, value: TokenStream) -> TokenStream {
+        let ident = &self.ident;
+        match &self.field {
+            Some(field) => quote! { #enum_name::#ident { #field: #value } },
+            None => quote! { #enum_name::#ident(#value) },
+        }
+    }
+
+    /// The argument for `value_format` formatting the reference `value`, wrapped in an adapter
+    /// calling the display function if set
+    fn format_value(&self, value: TokenStream) -> TokenStream {
+        match &self.display_with {
+            Some(path) => quote! {{
+                struct DisplayWith<'a, T: ?::core::marker::Sized>(
+                    &'a T,
+                    fn(&T, &mut ::core::fmt::Formatter) -> ::core::fmt::Result,
+                );
+
+                impl<'a, T: ?::core::marker::Sized> ::core::fmt::Display for DisplayWith<'a, T> {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        (self.1)(self.0, f)
+                    }
+                }
+
+                DisplayWith(#value, #path)
+            }},
+            None => value,
+        }
+    }
+
+    /// Format string writing the `value` of a variant that doesn't store its string value
+    ///
+    /// Number variants write the number, nested variants prepend their name and delimiter to the
+    /// nested value
+    fn value_format(&self) -> Option<String> {
+        if self.display {
+            return Some(String::from("{}"));
+        }
+        match &self.pattern {
+            Some(Pattern::Number) => Some(String::from("{}")),
+            Some(Pattern::Nested(prefix)) => Some(format!(
+                "{}{{}}",
+                prefix.replace('{', "{{").replace('}', "}}")
+            )),
+            _ => None,
+        }
+    }
+
+    /// All strings that are parsed into this option, the canonical name first
+    fn strings(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.name).chain(self.aliases.iter())
+    }
+}
+
+/// Restricts the values stored in a catch-all variant
+enum Pattern {
+    /// `#[value(prefix = "...")]`, values starting with the prefix
+    Prefix(String),
+    /// `#[value(regex = "...")]`, values matching the regex
+    Regex(String),
+    /// `#[strnum(parse_number)]`, values that parse as the number stored in the variant
+    Number,
+    /// `#[strnum(nested)]`, values starting with the variant name and delimiter followed by a
+    /// value of the nested enum
+    Nested(String),
+    /// `#[strnum(validate = "...")]`, values accepted by the validation function
+    Validate(Path),
+}
+
+impl Pattern {
+    #[cfg(feature = "regex")]
+    fn regex(lit: &LitStr) -> Result<Self> {
+        if !cfg!(feature = "std") {
+            return Err(Error::new(
+                lit.span(),
+                "Regex patterns require the \"std\" feature",
+            ));
+        }
+        let pattern = lit.value();
+        regex::Regex::new(&pattern)
+            .map_err(|err| Error::new(lit.span(), format!("Invalid regex: {}", err)))?;
+        Ok(Pattern::Regex(pattern))
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn regex(lit: &LitStr) -> Result<Self> {
+        Err(Error::new(
+            lit.span(),
+            "Regex patterns require the \"regex\" feature",
+        ))
+    }
+
+    /// Expression checking if the `&str` expression `input` matches the pattern
+    ///
+    /// Regexes are compiled once on first use with the `regex` crate at `regex_crate` and always
+    /// match case-sensitively, numbers and nested values are parsed instead of matched
+    fn matches(
+        &self,
+        input: &TokenStream,
+        ascii_case_insensitive: bool,
+        regex_crate: &TokenStream,
+    ) -> TokenStream {
+        match self {
+            Pattern::Prefix(prefix) if ascii_case_insensitive => quote! {
+                #input.get(..#prefix.len()).map_or(false, |start| start.eq_ignore_ascii_case(#prefix))
+            },
+            Pattern::Prefix(prefix) => quote! { #input.starts_with(#prefix) },
+            Pattern::Number | Pattern::Nested(_) => {
+                unreachable!("numbers and nested values are parsed instead of matched")
+            }
+            Pattern::Validate(path) => quote! { #path(#input) },
+            Pattern::Regex(regex) => quote! {{
+                static REGEX: ::std::sync::OnceLock<#regex_crate::Regex> = ::std::sync::OnceLock::new();
+                REGEX
+                    .get_or_init(|| #regex_crate::Regex::new(#regex).unwrap())
+                    .is_match(#input)
+            }},
+        }
+    }
+}
+
+/// The type stored in the catch-all variant
+enum FallbackType {
+    /// `String`
+    String,
+    /// Any other type that can be converted from `String` and `&str` and dereferences to `str`
+    ///
+    /// For example `Box<str>`, `Arc<str>` or small string types like `SmolStr`
+    Other,
+    /// `Cow<'a, str>`, which borrows when converting from `&'a str`
+    Cow(Lifetime),
+    /// `&'a str`, which can only be converted from `&'a str`
+    Str(Lifetime),
+}
+
+impl FallbackType {
+    fn from_type(ty: &Type) -> Self {
+        if let Type::Reference(TypeReference {
+            lifetime: Some(lifetime),
+            mutability: None,
+            elem,
+            ..
+        }) = ty
+        {
+            if let Type::Path(TypePath { qself: None, path }) = &**elem {
+                if path.segments.len() == 1 && path.segments[0].ident == "str" {
+                    return FallbackType::Str(lifetime.clone());
+                }
+            }
+        }
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(segment) = path.segments.iter().last() {
+                match &segment.arguments {
+                    PathArguments::None if segment.ident == "String" => {
+                        return FallbackType::String;
+                    }
+                    PathArguments::AngleBracketed(arguments) if segment.ident == "Cow" => {
+                        if let Some(GenericArgument::Lifetime(lifetime)) =
+                            arguments.args.iter().next()
+                        {
+                            return FallbackType::Cow(lifetime.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        FallbackType::Other
+    }
+
+    /// Whether the catch-all value can be created from an owned `String`
+    fn is_owned(&self) -> bool {
+        match self {
+            FallbackType::Str(_) => false,
+            _ => true,
+        }
+    }
+
+    /// The borrowed input type for the `From` implementation
+    fn borrowed_str(&self) -> TokenStream {
+        match self {
+            FallbackType::String | FallbackType::Other => quote! { &str },
+            FallbackType::Cow(lifetime) | FallbackType::Str(lifetime) => quote! { &#lifetime str },
+        }
+    }
+
+    /// Convert the catch-all value named `value` into a `String`
+    fn into_string(&self, string_type: &TokenStream) -> TokenStream {
+        match self {
+            FallbackType::String => quote! { value },
+            FallbackType::Other => quote! { #string_type::from(&*value) },
+            FallbackType::Cow(_) => quote! { value.into_owned() },
+            FallbackType::Str(_) => quote! { #string_type::from(value) },
+        }
+    }
+}
+
+/// Reject unknown options in `#[strnum(...)]` and malformed `#[value]`, `#[name]` and `#[alias]`
+/// attributes
+fn validate_attributes(
+    attrs: &[Attribute],
+    flags: &[&str],
+    keys: &[&str],
+    variant: bool,
+) -> Result<()> {
+    for attr in attrs {
+        if attr.path.segments.len() != 1 {
+            continue;
+        }
+        let name = attr.path.segments[0].ident.to_string();
+        match name.as_str() {
+            "strnum" => match attr.parse_meta()? {
+                Meta::List(list) => {
+                    for nested in list.nested.iter() {
+                        let valid = match nested {
+                            NestedMeta::Meta(Meta::Word(word)) => {
+                                flags.iter().any(|flag| word == flag)
+                            }
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                ident,
+                                lit: Lit::Str(_),
+                                ..
+                            })) => keys.iter().any(|key| ident == key),
+                            NestedMeta::Meta(Meta::List(list)) => list.ident == "props",
+                            _ => false,
+                        };
+                        if !valid {
+                            let expected = flags
+                                .iter()
+                                .map(|flag| format!("`{}`", flag))
+                                .chain(keys.iter().map(|key| format!("`{} = \"...\"`", key)))
+                                .chain(std::iter::once(String::from("`props(...)`")))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            return Err(Error::new(
+                                nested.span(),
+                                format!("Unknown strnum option, expected one of {}", expected),
+                            ));
+                        }
+                    }
+                }
+                meta => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "Expected options like `#[strnum(...)]`",
+                    ))
+                }
+            },
+            "value" | "name" | "alias" if !variant => {
+                return Err(Error::new(
+                    attr.span(),
+                    format!("`#[{}]` can only be used on variants", name),
+                ))
+            }
+            "value" | "name" | "alias" => match attr.parse_meta()? {
+                Meta::NameValue(MetaNameValue {
+                    lit: Lit::Str(_), ..
+                }) => {}
+                Meta::List(list) if name == "value" => {
+                    for nested in list.nested.iter() {
+                        match nested {
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                ident,
+                                lit: Lit::Str(_),
+                                ..
+                            })) if VALUE_KEYS.iter().any(|key| ident == key)
+                                || is_locale(&ident.to_string()) => {}
+                            NestedMeta::Meta(Meta::Word(word)) if word == "deprecated" => {}
+                            _ => {
+                                return Err(Error::new(
+                                    nested.span(),
+                                    "Unknown value option, expected `prefix = \"...\"`, `regex = \"...\"`, `short = \"...\"`, `long = \"...\"`, `parse_with = \"...\"`, `display_with = \"...\"`, `deprecated` or a localized value like `en = \"...\"`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                Meta::List(list) if name == "alias" => {
+                    for nested in list.nested.iter() {
+                        match nested {
+                            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                ident,
+                                lit: Lit::Str(_),
+                                ..
+                            })) if ident == "deprecated" => {}
+                            _ => {
+                                return Err(Error::new(
+                                    nested.span(),
+                                    "Unknown alias option, expected `deprecated = \"...\"`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                meta => {
+                    return Err(Error::new(
+                        meta.span(),
+                        format!("Expected a string like `#[{} = \"...\"]`", name),
+                    ))
+                }
+            },
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Integration crates whose path can be overridden on the enum, with the name of the crate as key
+/// like `#[strnum(serde = "...")]`
+const CRATES: &[&str] = &[
+    "arbitrary",
+    "async_graphql",
+    "clap",
+    "dialoguer",
+    "diesel",
+    "http",
+    "juniper",
+    "miniserde",
+    "phf",
+    "postgres_types",
+    "pyo3",
+    "quickcheck",
+    "rand",
+    "regex",
+    "rusqlite",
+    "schemars",
+    "sea_orm",
+    "serde",
+    "sqlx",
+    "utoipa",
+    "wasm_bindgen",
+];
+
+/// Get the integration crate paths overridden on the enum or newtype, keyed by name in `CRATES`
+fn get_crate_paths(attrs: &[Attribute]) -> Result<Vec<(String, Path)>> {
+    CRATES
+        .iter()
+        .filter_map(|name| get_attribute_str(attrs, "strnum", name).map(|path| (name, path)))
+        .map(|(name, path)| match path.parse::<Path>() {
+            Ok(parsed) => Ok((String::from(*name), parsed)),
+            Err(_) => Err(Error::new(
+                path.span(),
+                format!("Invalid crate path \"{}\"", path.value()),
+            )),
+        })
+        .collect()
+}
+
+/// The path to an integration crate, `::name` unless it's overridden
+fn crate_path(crates: &[(String, Path)], name: &str) -> TokenStream {
+    match crates.iter().find(|(krate, _)| krate == name) {
+        Some((_, path)) => quote! { #path },
+        None => {
+            let name = Ident::new(name, Span::call_site());
+            quote! { ::#name }
+        }
+    }
+}
+
+/// Options with a string value allowed in `#[value(...)]`, any other key is a locale
+const VALUE_KEYS: &[&str] = &[
+    "prefix",
+    "regex",
+    "short",
+    "long",
+    "parse_with",
+    "display_with",
+];
+
+/// Whether a `#[value(...)]` key looks like a language tag, a two or three letter language
+/// optionally followed by subtags separated by underscores like `en`, `pt_br` or `zh_hant`
+///
+/// This keeps misspelled options like `prefx` from silently becoming a locale.
+fn is_locale(key: &str) -> bool {
+    let mut subtags = key.split('_');
+    let language = subtags.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.bytes().all(|b| b.is_ascii_lowercase())
+        && subtags.all(|subtag| {
+            (2..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+}
+
+/// Get the values of all `#[name = "..."]` attributes
+fn get_attribute_values(attrs: &[Attribute], name: &str) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == name)
+        .filter_map(|meta| match meta {
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            }) => Some(lit.value()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Get the properties from `#[strnum(props(name = value))]`
+fn get_props(attrs: &[Attribute]) -> Result<Vec<(Ident, Lit)>> {
+    let mut props: Vec<(Ident, Lit)> = Vec::new();
+    let lists = attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == "strnum")
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            NestedMeta::Meta(Meta::List(list)) if list.ident == "props" => Some(list.nested),
+            _ => None,
+        })
+        .flatten();
+    for nested in lists {
+        match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { ident, lit, .. })) => {
+                if props.iter().any(|(existing, _)| *existing == ident) {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("Duplicate property `{}`", ident),
+                    ));
+                }
+                props.push((ident, lit));
+            }
+            nested => {
+                return Err(Error::new(
+                    nested.span(),
+                    "Expected properties like `props(name = value)`",
+                ))
+            }
+        }
+    }
+    Ok(props)
+}
+
+/// The type of a property literal, integers and floats default to `i32` and `f64` unless they
+/// have a suffix
+fn literal_type(lit: &Lit) -> Option<Type> {
+    match lit {
+        Lit::Str(_) => Some(parse_quote!(&'static str)),
+        Lit::ByteStr(_) => Some(parse_quote!(&'static [u8])),
+        Lit::Byte(_) => Some(parse_quote!(u8)),
+        Lit::Char(_) => Some(parse_quote!(char)),
+        Lit::Bool(_) => Some(parse_quote!(bool)),
+        Lit::Int(_) | Lit::Float(_) => {
+            let repr = quote!(#lit).to_string();
+            let is_float = matches!(lit, Lit::Float(_));
+            let suffix =
+                if repr.starts_with("0x") || repr.starts_with("0b") || repr.starts_with("0o") {
+                    repr[2..]
+                        .find(|c| c == 'u' || c == 'i')
+                        .map(|index| &repr[index + 2..])
+                } else {
+                    repr.find(|c| c == 'u' || c == 'i' || c == 'f')
+                        .map(|index| &repr[index..])
+                };
+            match suffix {
+                Some(suffix) => syn::parse_str(suffix).ok(),
+                None if is_float => Some(parse_quote!(f64)),
+                None => Some(parse_quote!(i32)),
+            }
+        }
+        Lit::Verbatim(_) => None,
+    }
+}
+
+/// Get the values of all `#[alias(deprecated = "...")]` attributes
+fn get_deprecated_aliases(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == "alias")
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                ident,
+                lit: Lit::Str(lit),
+                ..
+            })) if ident == "deprecated" => Some(lit.value()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Get the string literal from a `#[attribute(key = "...")]` style attribute
+fn get_attribute_str(attrs: &[Attribute], attribute: &str, key: &str) -> Option<LitStr> {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == attribute)
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                ident,
+                lit: Lit::Str(lit),
+                ..
+            })) if ident == key => Some(lit),
+            _ => None,
+        })
+        .next()
+}
+
+/// Get the localized values from `#[value(en = "...", de = "...")]`, any key that isn't a pattern
+/// or a short or long value is a locale
+fn get_locales(attrs: &[Attribute]) -> Result<Vec<(String, String)>> {
+    let mut locales: Vec<(String, String)> = Vec::new();
+    let values = attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == "value")
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list.nested),
+            _ => None,
+        })
+        .flatten();
+    for nested in values {
+        if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            ident,
+            lit: Lit::Str(lit),
+            ..
+        })) = nested
+        {
+            if VALUE_KEYS.iter().any(|key| ident == key) {
+                continue;
+            }
+            let locale = ident.to_string();
+            if locales.iter().any(|(existing, _)| *existing == locale) {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("Duplicate value for locale \"{}\"", locale),
+                ));
+            }
+            locales.push((locale, lit.value()));
         }
     }
+    Ok(locales)
+}
+
+/// The first paragraph of the doc comment, joined into a single line
+fn get_doc_summary(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter_map(|meta| match meta {
+            Meta::NameValue(MetaNameValue {
+                ident,
+                lit: Lit::Str(lit),
+                ..
+            }) if ident == "doc" => Some(lit.value().trim().to_string()),
+            _ => None,
+        })
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// Check for a `#[attribute(flag)]` style attribute
+fn has_attribute_flag(attrs: &[Attribute], attribute: &str, flag: &str) -> bool {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter(|meta| meta.name() == attribute)
+        .any(|meta| match meta {
+            Meta::List(list) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Word(word)) => word == flag,
+                _ => false,
+            }),
+            _ => false,
+        })
 }
@@ -0,0 +1,312 @@
+use crate::{
+    crate_path, get_attribute_str, get_attribute_values, get_crate_paths, has_attribute_flag,
+    string_path, validate_attributes, FallbackType, Pattern,
+};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, DataStruct, Error, Fields, Generics, Ident, Lit, Meta, MetaNameValue, NestedMeta,
+    Path, Result, Visibility,
+};
+
+/// Flags allowed in `#[strnum(...)]` on a newtype struct
+const FLAGS: &[&str] = &["ascii_case_insensitive", "trim"];
+/// Options with a string value allowed in `#[strnum(...)]` on a newtype struct, `regex` overrides
+/// the path of the regex crate
+const KEYS: &[&str] = &["validate", "regex"];
+
+/// Conversions for a tuple struct wrapping a single owned string
+///
+/// Without allowed values, a prefix, regex or validation function every string is accepted and
+/// the conversions are infallible. Otherwise the input is checked and the conversions return the
+/// generated `<Struct>Error` holding the rejected input.
+pub fn derive(
+    data: DataStruct,
+    name: &Ident,
+    vis: &Visibility,
+    generics: &Generics,
+    attrs: &[Attribute],
+) -> Result<TokenStream> {
+    let span = name.span();
+    validate_values(attrs)?;
+    validate_attributes(attrs, FLAGS, KEYS, true)?;
+
+    let field = match data.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            fields.unnamed.into_iter().next().unwrap()
+        }
+        _ => {
+            return Err(Error::new(
+                span,
+                "Can only derive StrNum for enums and tuple structs with a single string field",
+            ))
+        }
+    };
+    if !generics.params.is_empty() {
+        return Err(Error::new(span, "Newtype structs can't be generic"));
+    }
+    let ty = field.ty;
+    match FallbackType::from_type(&ty) {
+        FallbackType::String | FallbackType::Other => {}
+        FallbackType::Cow(_) | FallbackType::Str(_) => {
+            return Err(Error::new(
+                ty.span(),
+                "Newtype structs need an owned string type like `String`",
+            ))
+        }
+    }
+    let string_type = string_path()
+        .ok_or_else(|| Error::new(span, "Newtype structs require the \"alloc\" feature"))?;
+
+    let ascii_case_insensitive = has_attribute_flag(attrs, "strnum", "ascii_case_insensitive");
+    let input = quote!(value);
+    let regex_crate = crate_path(&get_crate_paths(attrs)?, "regex");
+    let mut checks = Vec::new();
+    let values = get_attribute_values(attrs, "value");
+    if !values.is_empty() {
+        checks.push(if ascii_case_insensitive {
+            quote! {
+                [#(#values),*].iter().any(|allowed: &&str| value.eq_ignore_ascii_case(allowed))
+            }
+        } else {
+            quote! { ::core::matches!(value, #(#values)|*) }
+        });
+    }
+    let pattern = match (
+        get_attribute_str(attrs, "value", "prefix"),
+        get_attribute_str(attrs, "value", "regex"),
+    ) {
+        (Some(_), Some(regex)) => {
+            return Err(Error::new(
+                regex.span(),
+                "A newtype struct can't have both a prefix and a regex",
+            ))
+        }
+        (Some(prefix), None) => Some(Pattern::Prefix(prefix.value())),
+        (None, Some(regex)) => Some(Pattern::regex(&regex)?),
+        (None, None) => None,
+    };
+    if let Some(pattern) = pattern {
+        checks.push(pattern.matches(&input, ascii_case_insensitive, &regex_crate));
+    }
+    if let Some(path) = get_attribute_str(attrs, "strnum", "validate") {
+        let path = path.parse::<Path>().map_err(|_| {
+            Error::new(
+                path.span(),
+                format!("Invalid validation function \"{}\"", path.value()),
+            )
+        })?;
+        checks.push(Pattern::Validate(path).matches(&input, ascii_case_insensitive, &regex_crate));
+    }
+
+    let (trim_borrowed, trim_owned) = if has_attribute_flag(attrs, "strnum", "trim") {
+        (
+            quote_spanned! { span =>
+                let value = value.trim();
+            },
+            quote_spanned! { span =>
+                let value = if value.trim().len() != value.len() {
+                    #string_type::from(value.trim())
+                } else {
+                    value
+                };
+            },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    let wrap = quote_spanned! { span =>
+        #name(<#ty as ::core::convert::From<#string_type>>::from(value))
+    };
+    let into_string = match FallbackType::from_type(&ty) {
+        FallbackType::String => quote_spanned! { span => from.0 },
+        _ => quote_spanned! { span => #string_type::from(&*from.0) },
+    };
+
+    let conversions = match checks.into_iter().reduce(|a, b| quote! { #a && #b }) {
+        None => quote_spanned! { span =>
+            impl ::core::convert::From<&str> for #name {
+                fn from(value: &str) -> Self {
+                    #trim_borrowed
+                    let value = #string_type::from(value);
+                    #wrap
+                }
+            }
+
+            impl ::core::convert::From<#string_type> for #name {
+                fn from(value: #string_type) -> Self {
+                    #trim_owned
+                    #wrap
+                }
+            }
+
+            impl ::core::str::FromStr for #name {
+                type Err = ::core::convert::Infallible;
+
+                fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                    ::core::result::Result::Ok(<Self as ::core::convert::From<&str>>::from(value))
+                }
+            }
+        },
+        Some(check) => {
+            let error_name = Ident::new(&format!("{}Error", name), span);
+            let error_doc = format!(
+                "Error returned when converting an invalid value into [`{}`]",
+                name
+            );
+            let message = format!("invalid value \"{{}}\" for {}", name);
+            let std_error = if cfg!(feature = "std") {
+                quote_spanned! { span =>
+                    impl ::std::error::Error for #error_name {}
+                }
+            } else {
+                TokenStream::new()
+            };
+
+            quote_spanned! { span =>
+                #[doc = #error_doc]
+                #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+                #vis struct #error_name {
+                    value: #string_type,
+                }
+
+                impl #error_name {
+                    /// The input that was rejected
+                    pub fn value(&self) -> &str {
+                        &self.value
+                    }
+
+                    /// Take the input that was rejected
+                    pub fn into_value(self) -> #string_type {
+                        self.value
+                    }
+                }
+
+                impl ::core::fmt::Display for #error_name {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(f, #message, self.value)
+                    }
+                }
+
+                #std_error
+
+                impl #name {
+                    /// Whether the value is accepted when converting into this type
+                    pub fn is_valid(value: &str) -> bool {
+                        #trim_borrowed
+                        #check
+                    }
+                }
+
+                impl ::core::convert::TryFrom<&str> for #name {
+                    type Error = #error_name;
+
+                    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                        #trim_borrowed
+                        let valid = #check;
+                        let value = #string_type::from(value);
+                        if valid {
+                            ::core::result::Result::Ok(#wrap)
+                        } else {
+                            ::core::result::Result::Err(#error_name { value })
+                        }
+                    }
+                }
+
+                impl ::core::convert::TryFrom<#string_type> for #name {
+                    type Error = #error_name;
+
+                    fn try_from(value: #string_type) -> ::core::result::Result<Self, Self::Error> {
+                        #trim_owned
+                        let valid = {
+                            let value: &str = &value;
+                            #check
+                        };
+                        if valid {
+                            ::core::result::Result::Ok(#wrap)
+                        } else {
+                            ::core::result::Result::Err(#error_name { value })
+                        }
+                    }
+                }
+
+                impl ::core::str::FromStr for #name {
+                    type Err = #error_name;
+
+                    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
+                        <Self as ::core::convert::TryFrom<&str>>::try_from(value)
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(quote_spanned! { span =>
+        #conversions
+
+        impl #name {
+            /// The wrapped string value
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Take the wrapped value
+            pub fn into_inner(self) -> #ty {
+                self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl ::core::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.pad(&self.0)
+            }
+        }
+
+        impl ::core::convert::From<#name> for #string_type {
+            fn from(from: #name) -> Self {
+                #into_string
+            }
+        }
+    })
+}
+
+/// Newtype structs don't support the variant options like locales, aliases or deprecation, only
+/// allowed values, a prefix and a regex
+fn validate_values(attrs: &[Attribute]) -> Result<()> {
+    for attr in attrs {
+        let valid = if attr.path.is_ident("alias") || attr.path.is_ident("name") {
+            false
+        } else if attr.path.is_ident("value") {
+            match attr.parse_meta()? {
+                Meta::NameValue(MetaNameValue {
+                    lit: Lit::Str(_), ..
+                }) => true,
+                Meta::List(list) => list.nested.iter().all(|nested| match nested {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        ident,
+                        lit: Lit::Str(_),
+                        ..
+                    })) => ident == "prefix" || ident == "regex",
+                    _ => false,
+                }),
+                _ => false,
+            }
+        } else {
+            true
+        };
+        if !valid {
+            return Err(Error::new(
+                attr.span(),
+                "Newtype structs only accept `#[value = \"...\"]` and `#[value(prefix = \"...\")]` or `#[value(regex = \"...\")]`",
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    #[value = "two"]
+    Two,
+}
+
+#[test]
+fn test_arbitrary() {
+    let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+
+    let mut u = Unstructured::new(&data);
+    for _ in 0..32 {
+        let value = LimitedValues::arbitrary(&mut u).unwrap();
+        assert!(LimitedValues::VARIANTS.contains(&value));
+    }
+
+    let mut u = Unstructured::new(&data);
+    for _ in 0..32 {
+        let value = Values::arbitrary(&mut u).unwrap();
+        assert_eq!(value, Values::from(value.as_str()));
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{value, EmptyMutation, EmptySubscription, Object, Schema};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    /// The first value
+    One,
+    #[value(deprecated)]
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum SpacedValues {
+    #[value = "New York"]
+    NewYork,
+    Tokyo,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn values(&self, value: Values) -> Values {
+        value
+    }
+
+    async fn limited(&self, value: LimitedValues) -> LimitedValues {
+        value
+    }
+
+    async fn spaced(&self, value: SpacedValues) -> SpacedValues {
+        value
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+#[tokio::test]
+async fn test_round_trip() {
+    let response = schema()
+        .execute(r#"{ one: values(value: "two") other: values(value: "Three") limited(value: Two) spaced(value: "New York") }"#)
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        value!({ "one": "two", "other": "Three", "limited": "Two", "spaced": "New York" }),
+        response.data
+    );
+
+    let response = schema().execute(r#"{ limited(value: Three) }"#).await;
+    assert!(!response.errors.is_empty());
+}
+
+#[test]
+fn test_schema() {
+    let sdl = schema().sdl();
+    assert!(sdl.contains("scalar Values"), "{}", sdl);
+    assert!(sdl.contains("scalar SpacedValues"), "{}", sdl);
+    assert!(sdl.contains("enum LimitedValues"), "{}", sdl);
+    assert!(sdl.contains("Two @deprecated"), "{}", sdl);
+}
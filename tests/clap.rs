@@ -0,0 +1,52 @@
+#![cfg(feature = "clap")]
+
+use clap::{CommandFactory, Parser, ValueEnum};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq, Clone)]
+enum Values {
+    One,
+    #[alias = "2"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq, Clone)]
+#[strnum(rename_all = "lowercase")]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long)]
+    value: Values,
+    #[arg(long, value_enum)]
+    limited: Option<LimitedValues>,
+}
+
+#[test]
+fn test_value_enum() {
+    let args = Args::try_parse_from(["test", "--value", "One", "--limited", "two"]).unwrap();
+    assert_eq!(Values::One, args.value);
+    assert_eq!(Some(LimitedValues::Two), args.limited);
+
+    let args = Args::try_parse_from(["test", "--value", "2"]).unwrap();
+    assert_eq!(Values::Two, args.value);
+
+    let args = Args::try_parse_from(["test", "--value", "Three"]).unwrap();
+    assert_eq!(Values::Other("Three".to_string()), args.value);
+
+    assert!(Args::try_parse_from(["test", "--value", "One", "--limited", "three"]).is_err());
+
+    let names: Vec<_> = LimitedValues::value_variants()
+        .iter()
+        .filter_map(|value| value.to_possible_value())
+        .map(|value| value.get_name().to_string())
+        .collect();
+    assert_eq!(vec!["one", "two"], names);
+
+    let help = Args::command().render_help().to_string();
+    assert!(help.contains("One, Two"));
+}
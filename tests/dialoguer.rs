@@ -0,0 +1,18 @@
+#![cfg(feature = "dialoguer")]
+
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[test]
+fn test_select_items() {
+    assert_eq!(&["One", "two"], Values::select_items());
+    assert_eq!(Some(Values::Two), Values::from_selection(1));
+    assert_eq!(None, Values::from_selection(2));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "diesel")]
+
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use diesel::{AsExpression, FromSqlRow};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+diesel::table! {
+    test (id) {
+        id -> Integer,
+        value -> Text,
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE test (id INTEGER PRIMARY KEY, value TEXT NOT NULL)")
+        .execute(&mut connection)
+        .unwrap();
+    for value in vec![Values::One, Values::Two, Values::Other("Three".to_string())] {
+        diesel::insert_into(test::table)
+            .values(test::value.eq(value))
+            .execute(&mut connection)
+            .unwrap();
+    }
+
+    let raw: Vec<String> = test::table
+        .select(test::value)
+        .order(test::id)
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(vec!["One", "two", "Three"], raw);
+
+    let values: Vec<Values> = test::table
+        .select(test::value)
+        .order(test::id)
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(
+        vec![Values::One, Values::Two, Values::Other("Three".to_string())],
+        values
+    );
+
+    let limited: QueryResult<LimitedValues> =
+        diesel::select(diesel::dsl::sql::<Text>("'Three'")).get_result(&mut connection);
+    assert!(limited.is_err());
+    let limited: LimitedValues = diesel::select(diesel::dsl::sql::<Text>("'Two'"))
+        .get_result(&mut connection)
+        .unwrap();
+    assert_eq!(LimitedValues::Two, limited);
+}
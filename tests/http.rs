@@ -0,0 +1,56 @@
+#![cfg(feature = "http")]
+
+use http::HeaderValue;
+use std::convert::TryFrom;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Encoding {
+    #[value = "gzip"]
+    Gzip,
+    #[value = "br"]
+    Brotli,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum CacheControl {
+    #[value = "no-cache"]
+    NoCache,
+    #[value = "no-store"]
+    NoStore,
+}
+
+#[test]
+fn test_into_header() {
+    assert_eq!(
+        HeaderValue::from_static("no-store"),
+        HeaderValue::from(CacheControl::NoStore)
+    );
+    assert_eq!(
+        HeaderValue::from_static("br"),
+        HeaderValue::try_from(Encoding::Brotli).unwrap()
+    );
+    assert_eq!(
+        HeaderValue::from_static("zstd"),
+        HeaderValue::try_from(Encoding::Other("zstd".into())).unwrap()
+    );
+    assert!(HeaderValue::try_from(Encoding::Other("a\nb".into())).is_err());
+}
+
+#[test]
+fn test_from_header() {
+    assert_eq!(
+        Ok(CacheControl::NoCache),
+        CacheControl::try_from(&HeaderValue::from_static("no-cache"))
+    );
+    assert!(CacheControl::try_from(&HeaderValue::from_static("private")).is_err());
+    assert_eq!(
+        Encoding::Gzip,
+        Encoding::from(&HeaderValue::from_static("gzip"))
+    );
+    assert_eq!(
+        Encoding::Other("zstd".into()),
+        Encoding::from(&HeaderValue::from_static("zstd"))
+    );
+}
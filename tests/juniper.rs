@@ -0,0 +1,87 @@
+#![cfg(feature = "juniper")]
+
+use juniper::{
+    graphql_object, graphql_value, EmptyMutation, EmptySubscription, RootNode, Variables,
+};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    /// The first value
+    One,
+    #[value(deprecated)]
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum SpacedValues {
+    #[value = "New York"]
+    NewYork,
+    Tokyo,
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn values(value: Values) -> Values {
+        value
+    }
+
+    fn limited(value: LimitedValues) -> LimitedValues {
+        value
+    }
+
+    fn spaced(value: SpacedValues) -> SpacedValues {
+        value
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+fn schema() -> Schema {
+    Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[test]
+fn test_round_trip() {
+    let (result, errors) = juniper::execute_sync(
+        r#"{ one: values(value: "two") other: values(value: "Three") limited(value: Two) spaced(value: "New York") }"#,
+        None,
+        &schema(),
+        &Variables::new(),
+        &(),
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(
+        graphql_value!({ "one": "two", "other": "Three", "limited": "Two", "spaced": "New York" }),
+        result
+    );
+
+    let result = juniper::execute_sync(
+        r#"{ limited(value: Three) }"#,
+        None,
+        &schema(),
+        &Variables::new(),
+        &(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_schema() {
+    let sdl = schema().as_sdl();
+    assert!(sdl.contains("scalar Values"), "{}", sdl);
+    assert!(sdl.contains("scalar SpacedValues"), "{}", sdl);
+    assert!(sdl.contains("enum LimitedValues"), "{}", sdl);
+    assert!(sdl.contains("Two @deprecated"), "{}", sdl);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "miniserde")]
+
+use miniserde::json;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[test]
+fn test_serialize() {
+    assert_eq!("\"One\"", json::to_string(&Values::One));
+    assert_eq!("\"two\"", json::to_string(&Values::Two));
+    assert_eq!("\"Three\"", json::to_string(&Values::Other("Three".into())));
+    assert_eq!("\"Two\"", json::to_string(&LimitedValues::Two));
+}
+
+#[test]
+fn test_deserialize() {
+    assert_eq!(Values::Two, json::from_str::<Values>("\"two\"").unwrap());
+    assert_eq!(
+        Values::Other("Three".into()),
+        json::from_str::<Values>("\"Three\"").unwrap()
+    );
+    assert_eq!(
+        LimitedValues::One,
+        json::from_str::<LimitedValues>("\"One\"").unwrap()
+    );
+    assert!(json::from_str::<LimitedValues>("\"Three\"").is_err());
+}
@@ -0,0 +1,34 @@
+use std::convert::TryFrom;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(lookup = "phf")]
+enum Values {
+    One,
+    #[alias = "2"]
+    Two,
+    #[value = "three"]
+    Three,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(lookup = "phf")]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[test]
+fn test_phf_lookup() {
+    assert_eq!(Values::One, "One".into());
+    assert_eq!(Values::Two, "2".to_string().into());
+    assert_eq!(Values::Three, "three".parse().unwrap());
+    assert_eq!(Values::Other("Three".to_string()), "Three".into());
+
+    assert_eq!(Ok(LimitedValues::Two), LimitedValues::try_from("Two"));
+    assert_eq!(
+        "three",
+        LimitedValues::try_from("three").unwrap_err().value()
+    );
+}
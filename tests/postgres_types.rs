@@ -0,0 +1,67 @@
+#![cfg(feature = "postgres-types")]
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, Kind, ToSql, Type};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum BorrowedValues<'a> {
+    One,
+    Other(&'a str),
+}
+
+fn encode<T: ToSql>(value: &T, ty: &Type) -> BytesMut {
+    let mut out = BytesMut::new();
+    value.to_sql_checked(ty, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn test_round_trip() {
+    for value in vec![Values::One, Values::Two, Values::Other("Three".to_string())] {
+        let raw = encode(&value, &Type::TEXT);
+        assert_eq!(value.as_str().as_bytes(), &raw[..]);
+        assert_eq!(value, Values::from_sql(&Type::TEXT, &raw).unwrap());
+    }
+
+    assert!(LimitedValues::from_sql(&Type::TEXT, b"Three").is_err());
+    assert_eq!(
+        LimitedValues::Two,
+        LimitedValues::from_sql(&Type::VARCHAR, b"Two").unwrap()
+    );
+
+    let raw = b"Three".to_vec();
+    assert_eq!(
+        BorrowedValues::Other("Three"),
+        BorrowedValues::from_sql(&Type::TEXT, &raw).unwrap()
+    );
+}
+
+#[test]
+fn test_enum_type() {
+    let ty = Type::new(
+        "values".to_string(),
+        0,
+        Kind::Enum(vec!["One".to_string(), "two".to_string()]),
+        "public".to_string(),
+    );
+    assert!(<Values as ToSql>::accepts(&ty));
+    assert!(<Values as FromSql>::accepts(&ty));
+    assert!(!<Values as ToSql>::accepts(&Type::INT4));
+    assert_eq!(&b"two"[..], &encode(&Values::Two, &ty)[..]);
+    assert_eq!(Values::Two, Values::from_sql(&ty, b"two").unwrap());
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "pyo3")]
+
+use pyo3::exceptions::PyValueError;
+use pyo3::types::PyString;
+use pyo3::{IntoPy, Python, ToPyObject};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum BorrowedValues<'a> {
+    One,
+    Other(&'a str),
+}
+
+#[test]
+fn test_round_trip() {
+    Python::with_gil(|py| {
+        let object = Values::Two.into_py(py);
+        assert_eq!("two", object.extract::<String>(py).unwrap());
+        assert_eq!(Values::Two, object.extract::<Values>(py).unwrap());
+        assert_eq!(
+            "One",
+            Values::One.to_object(py).extract::<String>(py).unwrap()
+        );
+
+        let other = "Three".to_object(py);
+        assert_eq!(
+            Values::Other("Three".to_string()),
+            other.extract::<Values>(py).unwrap()
+        );
+        let error = other.extract::<LimitedValues>(py).unwrap_err();
+        assert!(error.is_instance_of::<PyValueError>(py));
+        assert_eq!(
+            LimitedValues::Two,
+            "Two".to_object(py).extract::<LimitedValues>(py).unwrap()
+        );
+        assert!(1.to_object(py).extract::<Values>(py).is_err());
+
+        let string = PyString::new(py, "Three");
+        assert_eq!(
+            BorrowedValues::Other("Three"),
+            string.extract::<BorrowedValues>().unwrap()
+        );
+    });
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "quickcheck")]
+
+use quickcheck::{quickcheck, Arbitrary};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, Clone, PartialEq)]
+enum Values {
+    One,
+    Two,
+    Three,
+    Other(String),
+}
+
+#[test]
+fn test_shrink() {
+    assert_eq!(
+        Vec::<Values>::new(),
+        Values::One.shrink().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Values::One, Values::Two],
+        Values::Three.shrink().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Values::One, Values::Two, Values::Three],
+        Values::from("foo").shrink().take(3).collect::<Vec<_>>()
+    );
+}
+
+quickcheck! {
+    fn roundtrip(value: Values) -> bool {
+        value == Values::from(value.as_str())
+    }
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "rand")]
+
+use rand::Rng;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    Two,
+    Other(String),
+}
+
+#[test]
+fn test_sample() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..32 {
+        let value: Values = rng.gen();
+        assert!(Values::VARIANTS.contains(&value));
+    }
+}
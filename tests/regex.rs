@@ -0,0 +1,43 @@
+#![cfg(feature = "regex")]
+
+use std::convert::TryFrom;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Versions {
+    Latest,
+    #[value(regex = "^v[0-9]+$")]
+    Version(String),
+    #[value(prefix = "v")]
+    Named(String),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedVersions {
+    Latest,
+    #[value(regex = "^v[0-9]+$")]
+    Version(String),
+}
+
+#[test]
+fn test_regex() {
+    assert_eq!(Versions::Latest, "Latest".into());
+    assert_eq!(Versions::Version("v12".into()), "v12".into());
+    assert_eq!(Versions::Named("vNext".into()), "vNext".into());
+    assert_eq!(Versions::Other("12".into()), String::from("12").into());
+    assert_eq!("v12", Versions::from("v12").as_str());
+}
+
+#[test]
+fn test_regex_limited() {
+    assert_eq!(
+        Ok(LimitedVersions::Version("v3".into())),
+        LimitedVersions::try_from("v3")
+    );
+    assert!(LimitedVersions::try_from("v3.1").is_err());
+    assert_eq!(
+        &["Latest", "^v[0-9]+$"],
+        LimitedVersions::try_from("3").unwrap_err().expected()
+    );
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "rusqlite")]
+
+use rusqlite::Connection;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[test]
+fn test_round_trip() {
+    let connection = Connection::open_in_memory().unwrap();
+    connection
+        .execute("CREATE TABLE test (value TEXT NOT NULL)", [])
+        .unwrap();
+    for value in &[Values::One, Values::Two, Values::Other("Three".to_string())] {
+        connection
+            .execute("INSERT INTO test (value) VALUES (?1)", [value])
+            .unwrap();
+    }
+
+    let raw: Vec<String> = connection
+        .prepare("SELECT value FROM test")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(vec!["One", "two", "Three"], raw);
+
+    let values: Vec<Values> = connection
+        .prepare("SELECT value FROM test")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        vec![Values::One, Values::Two, Values::Other("Three".to_string())],
+        values
+    );
+
+    let limited: rusqlite::Result<LimitedValues> =
+        connection.query_row("SELECT 'Three'", [], |row| row.get(0));
+    assert!(limited.is_err());
+    let limited: LimitedValues = connection
+        .query_row("SELECT 'Two'", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(LimitedValues::Two, limited);
+}
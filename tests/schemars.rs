@@ -0,0 +1,30 @@
+#![cfg(feature = "schemars")]
+
+use schemars::schema_for;
+use serde_json::json;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    #[value = "two"]
+    Two,
+}
+
+#[test]
+fn test_schema() {
+    let schema = serde_json::to_value(schema_for!(Values)).unwrap();
+    assert_eq!(json!("string"), schema["type"]);
+    assert_eq!(None, schema.get("enum"));
+
+    let schema = serde_json::to_value(schema_for!(LimitedValues)).unwrap();
+    assert_eq!(json!("string"), schema["type"]);
+    assert_eq!(json!(["One", "two"]), schema["enum"]);
+}
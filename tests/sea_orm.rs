@@ -0,0 +1,69 @@
+#![cfg(feature = "sea-orm")]
+
+use sea_orm::sea_query::{Nullable, ValueType};
+use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase, Value};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq, Clone)]
+pub enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+mod entity {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "test")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub value: super::Values,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn test_value() {
+    let value: Value = Values::Two.into();
+    assert_eq!(Value::from("two"), value);
+    assert_eq!(Values::Two, <Values as ValueType>::try_from(value).unwrap());
+    assert_eq!(
+        Values::Other("Three".to_string()),
+        <Values as ValueType>::try_from(Value::from("Three")).unwrap()
+    );
+    assert_eq!(Value::String(None), Values::null());
+
+    assert!(<LimitedValues as ValueType>::try_from(Value::from("Three")).is_err());
+    assert!(<LimitedValues as ValueType>::try_from(Value::from(1)).is_err());
+}
+
+#[tokio::test]
+async fn test_query() {
+    let models = vec![
+        entity::Model {
+            id: 1,
+            value: Values::Two,
+        },
+        entity::Model {
+            id: 2,
+            value: Values::Other("Three".to_string()),
+        },
+    ];
+    let db = MockDatabase::new(DatabaseBackend::Sqlite)
+        .append_query_results([models.clone()])
+        .into_connection();
+    assert_eq!(models, entity::Entity::find().all(&db).await.unwrap());
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum BorrowedValues<'a> {
+    One,
+    Other(Cow<'a, str>),
+}
+
+mod facade {
+    pub use serde;
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(serde = "facade::serde")]
+enum ReexportedValues {
+    One,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(serde_repr = "index")]
+enum IndexValues {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Container {
+    value: Values,
+    limited: LimitedValues,
+}
+
+#[test]
+fn test_serialize() {
+    assert_eq!("\"One\"", serde_json::to_string(&Values::One).unwrap());
+    assert_eq!("\"two\"", serde_json::to_string(&Values::Two).unwrap());
+    assert_eq!(
+        "\"Three\"",
+        serde_json::to_string(&Values::Other("Three".to_string())).unwrap()
+    );
+    assert_eq!(
+        r#"{"value":"two","limited":"One"}"#,
+        serde_json::to_string(&Container {
+            value: Values::Two,
+            limited: LimitedValues::One
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    assert_eq!(Values::One, serde_json::from_str("\"One\"").unwrap());
+    assert_eq!(Values::Two, serde_json::from_str("\"two\"").unwrap());
+    assert_eq!(
+        Values::Other("Three".to_string()),
+        serde_json::from_str("\"Three\"").unwrap()
+    );
+    assert_eq!(LimitedValues::Two, serde_json::from_str("\"Two\"").unwrap());
+
+    let error = serde_json::from_str::<LimitedValues>("\"Three\"").unwrap_err();
+    assert!(error.to_string().contains("unknown variant `Three`"));
+
+    match serde_json::from_str::<BorrowedValues>("\"Three\"").unwrap() {
+        BorrowedValues::Other(Cow::Borrowed(value)) => assert_eq!("Three", value),
+        value => panic!("expected a borrowed value, got {:?}", value),
+    }
+    assert_eq!(
+        BorrowedValues::Other("Three\n".into()),
+        serde_json::from_str("\"Three\\n\"").unwrap()
+    );
+}
+
+#[test]
+fn test_index() {
+    assert_eq!("\"Two\"", serde_json::to_string(&IndexValues::Two).unwrap());
+    assert_eq!(
+        r#"{"Other":"Three"}"#,
+        serde_json::to_string(&IndexValues::Other("Three".into())).unwrap()
+    );
+    assert_eq!(IndexValues::Two, serde_json::from_str("\"Two\"").unwrap());
+    assert_eq!(
+        IndexValues::Other("Three".into()),
+        serde_json::from_str(r#"{"Other":"Three"}"#).unwrap()
+    );
+
+    let encoded = bincode::serialize(&IndexValues::Two).unwrap();
+    assert_eq!(vec![1, 0, 0, 0], encoded);
+    assert_eq!(IndexValues::Two, bincode::deserialize(&encoded).unwrap());
+    let encoded = bincode::serialize(&IndexValues::Other("Three".into())).unwrap();
+    assert_eq!(
+        IndexValues::Other("Three".into()),
+        bincode::deserialize(&encoded).unwrap()
+    );
+}
+
+#[test]
+fn test_crate_path() {
+    assert_eq!(
+        "\"One\"",
+        serde_json::to_string(&ReexportedValues::One).unwrap()
+    );
+    assert_eq!(
+        ReexportedValues::Other("Three".into()),
+        serde_json::from_str("\"Three\"").unwrap()
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "sqlx")]
+
+use sqlx::{Connection, SqliteConnection};
+use strnum::StrNum;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[tokio::test]
+async fn test_round_trip() {
+    let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE test (value TEXT NOT NULL)")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+    for value in vec![Values::One, Values::Two, Values::Other("Three".to_string())] {
+        sqlx::query("INSERT INTO test (value) VALUES (?1)")
+            .bind(value)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+    }
+
+    let raw: Vec<String> = sqlx::query_scalar("SELECT value FROM test")
+        .fetch_all(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(vec!["One", "two", "Three"], raw);
+
+    let values: Vec<Values> = sqlx::query_scalar("SELECT value FROM test")
+        .fetch_all(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![Values::One, Values::Two, Values::Other("Three".to_string())],
+        values
+    );
+
+    let limited: Result<LimitedValues, _> = sqlx::query_scalar("SELECT 'Three'")
+        .fetch_one(&mut connection)
+        .await;
+    assert!(limited.is_err());
+    let limited: LimitedValues = sqlx::query_scalar("SELECT 'Two'")
+        .fetch_one(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(LimitedValues::Two, limited);
+}
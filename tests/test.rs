@@ -1,6 +1,10 @@
+use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::convert::TryInto;
-use strnum::StrNum;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::sync::Arc;
+use strnum::{roundtrip_tests, strnum_const, StrNum};
 
 #[derive(StrNum, Debug, PartialEq)]
 enum Values {
@@ -21,6 +25,580 @@ enum RenamedValues {
     Other(String),
 }
 
+#[derive(StrNum, Debug, PartialEq)]
+enum NamedValues {
+    #[name = "one"]
+    One,
+    #[name = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(rename_all = "kebab-case")]
+enum CasedValues {
+    FirstValue,
+    SecondValue,
+    #[value = "THIRD"]
+    ThirdValue,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum AliasedValues {
+    #[value = "New York"]
+    #[alias = "NYC"]
+    #[alias = "new-york"]
+    NewYork,
+    Amsterdam,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ascii_case_insensitive)]
+enum InsensitiveValues {
+    Get,
+    Post,
+    #[alias = "del"]
+    Delete,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LeadingFallbackValues {
+    Other(String),
+    One,
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(lookup = "length")]
+enum BucketedValues {
+    One,
+    Two,
+    #[alias = "3"]
+    Three,
+    Four,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(lookup = "length", ascii_case_insensitive)]
+enum BucketedLimitedValues {
+    Get,
+    Post,
+    Put,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum CowValues<'a> {
+    One,
+    Two,
+    Other(Cow<'a, str>),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum BorrowedValues<'a> {
+    One,
+    Two,
+    Other(&'a str),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum BoxedValues {
+    One,
+    Other(Box<str>),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum SharedValues {
+    One,
+    Other(Arc<str>),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum GenericValues<T>
+where
+    T: for<'a> From<&'a str> + From<String> + Deref<Target = str> + Display,
+{
+    One,
+    Two,
+    Other(T),
+}
+
+#[derive(Debug, PartialEq)]
+struct Tag(String);
+
+impl From<String> for Tag {
+    fn from(value: String) -> Self {
+        Tag(value.to_lowercase())
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum TagValues {
+    One,
+    #[strnum(display)]
+    Other(Tag),
+}
+
+#[derive(Debug, PartialEq)]
+struct Version(u32, u32);
+
+impl From<String> for Version {
+    fn from(value: String) -> Self {
+        let mut parts = value.splitn(2, '.').map(|part| part.parse().unwrap_or(0));
+        Version(parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+    }
+}
+
+fn format_version(version: &Version, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "v{}.{}", version.0, version.1)
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum VersionValues {
+    Latest,
+    #[value(display_with = "format_version")]
+    Pinned(Version),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum DisplayValues<T> {
+    One,
+    #[strnum(display)]
+    Other(T),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(doc_values)]
+enum HeaderValues {
+    Accept,
+    #[value = "Content-Type"]
+    ContentType,
+    #[value(prefix = "X-")]
+    Extension(String),
+}
+
+fn is_identifier(value: &str) -> bool {
+    (1..=16).contains(&value.len())
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte == b'_')
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum ValidatedValues {
+    One,
+    #[strnum(validate = "is_identifier")]
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(rename_all = "lowercase")]
+enum Transport {
+    Tcp,
+    Udp,
+    #[strnum(default_on_error)]
+    Unknown,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(skip_display, skip_into_string, skip_from_string)]
+enum HandWrittenValues {
+    One,
+    Two,
+    Other(String),
+}
+
+impl Display for HandWrittenValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "<{}>", self.as_str())
+    }
+}
+
+impl From<HandWrittenValues> for String {
+    fn from(value: HandWrittenValues) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<&str> for HandWrittenValues {
+    fn from(value: &str) -> Self {
+        match value.trim_start_matches('<').trim_end_matches('>') {
+            "One" => HandWrittenValues::One,
+            "Two" => HandWrittenValues::Two,
+            other => HandWrittenValues::Other(other.into()),
+        }
+    }
+}
+
+impl From<String> for HandWrittenValues {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl std::str::FromStr for HandWrittenValues {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(value.into())
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(
+    trim,
+    percent_decode,
+    normalize = "normalize_color",
+    rename_all = "lowercase",
+    store_original
+)]
+enum OriginalColor {
+    Red,
+    Gray,
+    #[value(prefix = "x-")]
+    Custom(String),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ascii_case_insensitive)]
+enum DeprecatedRegion {
+    #[value = "eu-west"]
+    #[alias(deprecated = "europe")]
+    EuWest,
+    #[value(deprecated)]
+    #[alias = "us"]
+    UsEast,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(props(code = "u16"))]
+enum HttpError {
+    #[value = "not-found"]
+    #[strnum(props(code = 404, retryable = false, reason = "Not Found"))]
+    NotFound,
+    #[value = "unavailable"]
+    #[strnum(props(code = 503, retryable = true, reason = "Service Unavailable"))]
+    Unavailable,
+    #[strnum(
+        skip,
+        props(code = 500, retryable = false, reason = "Internal Server Error")
+    )]
+    Internal(u32),
+    #[strnum(props(code = 500, retryable = true, reason = ""))]
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(rename_all = "lowercase")]
+enum CategorizedLogLevel {
+    #[strnum(category = "verbose")]
+    Trace,
+    #[strnum(category = "verbose")]
+    Debug,
+    Info,
+    #[strnum(category = "problem")]
+    Warn,
+    #[strnum(category = "problem")]
+    Error,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(trim)]
+struct Username(String);
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(validate = "is_identifier")]
+#[value(prefix = "app_")]
+struct AppName(Arc<str>);
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ascii_case_insensitive)]
+#[value = "en"]
+#[value = "de"]
+struct Language(String);
+
+fn parse_priority(value: &str) -> Option<Priority> {
+    match value.parse::<u8>() {
+        Ok(0..=4) => Some(Priority::Low),
+        Ok(5..=9) => Some(Priority::High),
+        _ => None,
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Priority {
+    #[value(parse_with = "parse_priority")]
+    Low,
+    High,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum StrictPriority {
+    Low,
+    #[value(parse_with = "StrictPriority::parse_high")]
+    High,
+}
+
+impl StrictPriority {
+    fn parse_high(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("urgent") {
+            Some(StrictPriority::High)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ascii_case_insensitive)]
+enum KeyValues {
+    Name,
+    #[value(prefix = "x-custom-")]
+    Custom(String),
+    #[value(prefix = "x-")]
+    Extension(Box<str>),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum StatusValues {
+    #[value = "200"]
+    Ok,
+    #[value = "404"]
+    NotFound,
+    #[strnum(parse_number)]
+    Code(u16),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(trim)]
+enum TrimmedValues {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(trim)]
+enum TrimmedLimitedValues {
+    One,
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum EmptyValues {
+    #[value = ""]
+    Empty,
+    One,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(trim)]
+enum UnsetValues {
+    #[strnum(empty)]
+    Unset,
+    One,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ascii_case_insensitive, preserve_case)]
+enum PreservedValues {
+    Amsterdam,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum NamedFieldValues {
+    One,
+    Two,
+    Other { value: String },
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(rename_all = "lowercase")]
+enum Region {
+    West,
+    North,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Zone {
+    Global,
+    #[value = "eu"]
+    #[strnum(nested)]
+    Europe(Region),
+    #[value = "us"]
+    #[strnum(nested, delimiter = "-")]
+    America(Region),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(repr = "u16", rename_all = "lowercase")]
+enum Protocol {
+    Tcp = 6,
+    Udp = 17,
+    #[strnum(skip)]
+    Reserved = 255,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum DescribedValues {
+    /// The first value
+    ///
+    /// More details that aren't part of the description.
+    One,
+    /// The second
+    /// value
+    #[strnum(message = "Value number two")]
+    Two,
+    /// The third
+    /// value
+    Three,
+    Four,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum State {
+    #[value(short = "NY", long = "New York")]
+    NewYork,
+    #[value(short = "CA")]
+    California,
+    Texas,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(abbrev, ascii_case_insensitive)]
+enum Command {
+    Add,
+    #[alias = "rm"]
+    Remove,
+    Rename,
+    Get,
+    GetAll,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(from_env, rename_all = "lowercase")]
+enum LogLevel {
+    Debug,
+    Info,
+    Warn,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(percent_decode)]
+enum EncodedValues {
+    #[value = "New York"]
+    NewYork,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(percent_decode, trim)]
+enum EncodedLimitedValues {
+    #[value = "a/b"]
+    Path,
+}
+
+fn normalize_color(value: &str) -> Cow<str> {
+    let value = value.trim_start_matches('\u{feff}');
+    match value {
+        "grey" => Cow::Borrowed("gray"),
+        _ if value.bytes().any(|byte| byte.is_ascii_uppercase()) => {
+            Cow::Owned(value.to_ascii_lowercase())
+        }
+        _ => Cow::Borrowed(value),
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(normalize = "normalize_color", rename_all = "lowercase")]
+enum Color {
+    Red,
+    Gray,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(normalize = "normalize_color", rename_all = "lowercase")]
+enum LimitedColor {
+    Red,
+    Gray,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ffi)]
+enum FfiValues {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(ffi)]
+enum FfiLimitedValues {
+    One,
+    Two,
+}
+
+#[derive(StrNum, PartialEq)]
+#[strnum(debug)]
+enum DebugValues {
+    #[value = "New York"]
+    NewYork,
+    #[strnum(parse_number)]
+    Number(u8),
+    #[value = "quoted"]
+    #[strnum(nested, delimiter = "\"")]
+    Quoted(LimitedValues),
+    #[strnum(skip)]
+    Internal(u8),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Weekday {
+    #[value(en = "Monday", de = "Montag")]
+    Mon,
+    #[value(en = "Tuesday")]
+    Tue,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(known_enum = "MethodKnown")]
+enum Method {
+    Get,
+    Post,
+    #[strnum(skip)]
+    Internal(u8),
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(set)]
+enum Feature {
+    One,
+    Two,
+    Three,
+}
+
 #[derive(StrNum, Debug, PartialEq)]
 enum LimitedValues {
     One,
@@ -28,63 +606,1216 @@ enum LimitedValues {
     Three,
 }
 
+#[derive(StrNum, Debug, PartialEq, Eq)]
+#[strnum(ord)]
+enum OrderedValues {
+    Zulu,
+    Alpha,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq, Eq)]
+#[strnum(hash)]
+enum HashedValues {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(str_eq)]
+enum ComparableValues {
+    One,
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum DefaultValues {
+    One,
+    #[strnum(default)]
+    Two,
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum DefaultFallbackValues {
+    One,
+    #[strnum(default)]
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum SkippedValues {
+    One,
+    #[strnum(skip)]
+    Pair(u8, u8),
+    Other(String),
+}
+
+#[derive(Debug, PartialEq)]
+struct UnknownValue(String);
+
+impl From<String> for UnknownValue {
+    fn from(value: String) -> Self {
+        UnknownValue(value)
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(error = "UnknownValue")]
+enum CustomErrorValues {
+    One,
+    Two,
+}
+
+#[derive(Debug, PartialEq)]
+struct UnknownLength(usize);
+
+impl UnknownLength {
+    fn new(value: &str) -> Self {
+        UnknownLength(value.len())
+    }
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+#[strnum(error = "UnknownLength", error_fn = "UnknownLength::new")]
+enum CustomErrorFnValues {
+    One,
+    Two,
+}
+
+#[test]
+fn test_values() {
+    assert_eq!(Values::One, "One".into());
+    assert_eq!(Values::Two, "Two".into());
+    assert_eq!(Values::Three, "Three".into());
+    assert_eq!(Values::Other("Four".to_string()), "Four".into());
+
+    assert_eq!(Ok(Values::One), "One".parse());
+    assert_eq!(Ok(Values::Other("Four".to_string())), "Four".parse());
+
+    assert_eq!("One", Values::to_string(&Values::One));
+    assert_eq!("Two", Values::to_string(&Values::Two));
+    assert_eq!("Three", Values::to_string(&Values::Three));
+    assert_eq!(
+        "Four",
+        Values::to_string(&Values::Other("Four".to_string()))
+    );
+
+    assert_eq!("One", String::from(Values::One));
+    assert_eq!("Two", String::from(Values::Two));
+    assert_eq!("Three", String::from(Values::Three));
+    assert_eq!("Four", String::from(Values::Other("Four".to_string())));
+
+    assert_eq!("One", Values::One.as_str());
+    assert_eq!("Four", Values::Other("Four".to_string()).as_str());
+}
+
+#[test]
+fn test_renamed() {
+    assert_eq!(RenamedValues::One, "one".into());
+    assert_eq!(RenamedValues::Two, "two".into());
+    assert_eq!(RenamedValues::Three, "three".into());
+    assert_eq!(RenamedValues::Other("four".to_string()), "four".into());
+
+    assert_eq!("one", RenamedValues::to_string(&RenamedValues::One));
+    assert_eq!("two", RenamedValues::to_string(&RenamedValues::Two));
+    assert_eq!("three", RenamedValues::to_string(&RenamedValues::Three));
+    assert_eq!(
+        "four",
+        RenamedValues::to_string(&RenamedValues::Other("four".to_string()))
+    );
+
+    assert_eq!("one", String::from(RenamedValues::One));
+    assert_eq!("two", String::from(RenamedValues::Two));
+    assert_eq!("three", String::from(RenamedValues::Three));
+    assert_eq!(
+        "four",
+        String::from(RenamedValues::Other("four".to_string()))
+    );
+}
+
+#[test]
+fn test_named() {
+    assert_eq!(NamedValues::One, "one".into());
+    assert_eq!(NamedValues::Two, "two".into());
+    assert_eq!(NamedValues::Other("One".to_string()), "One".into());
+    assert_eq!("one", NamedValues::One.as_str());
+}
+
+#[test]
+fn test_rename_all() {
+    assert_eq!(CasedValues::FirstValue, "first-value".into());
+    assert_eq!(CasedValues::SecondValue, "second-value".into());
+    assert_eq!(CasedValues::ThirdValue, "THIRD".into());
+    assert_eq!(
+        CasedValues::Other("FirstValue".to_string()),
+        "FirstValue".into()
+    );
+
+    assert_eq!("first-value", CasedValues::FirstValue.as_str());
+    assert_eq!("THIRD", CasedValues::ThirdValue.as_str());
+}
+
+#[test]
+fn test_alias() {
+    assert_eq!(AliasedValues::NewYork, "New York".into());
+    assert_eq!(AliasedValues::NewYork, "NYC".into());
+    assert_eq!(AliasedValues::NewYork, "new-york".into());
+    assert_eq!(AliasedValues::Amsterdam, "Amsterdam".into());
+    assert_eq!(AliasedValues::Other("nyc".to_string()), "nyc".into());
+
+    assert_eq!("New York", String::from(AliasedValues::from("NYC")));
+    assert_eq!("New York", AliasedValues::from("new-york").as_str());
+}
+
+#[test]
+fn test_ascii_case_insensitive() {
+    assert_eq!(InsensitiveValues::Get, "Get".into());
+    assert_eq!(InsensitiveValues::Get, "GET".into());
+    assert_eq!(InsensitiveValues::Post, "post".into());
+    assert_eq!(InsensitiveValues::Delete, "DEL".into());
+    assert_eq!(InsensitiveValues::Other("Püt".to_string()), "Püt".into());
+
+    assert_eq!("Get", InsensitiveValues::from("gEt").as_str());
+}
+
+#[test]
+fn test_limited() {
+    assert_eq!(Ok(LimitedValues::One), "One".try_into());
+    assert_eq!(Ok(LimitedValues::Two), "Two".try_into());
+    assert_eq!(Ok(LimitedValues::Three), "Three".try_into());
+    assert_eq!("four", LimitedValues::try_from("four").unwrap_err().value());
+
+    assert_eq!(Ok(LimitedValues::One), "One".parse());
+    assert_eq!(
+        "four",
+        "four".parse::<LimitedValues>().unwrap_err().into_value()
+    );
+
+    assert_eq!("One", LimitedValues::to_string(&LimitedValues::One));
+    assert_eq!("Two", LimitedValues::to_string(&LimitedValues::Two));
+    assert_eq!("Three", LimitedValues::to_string(&LimitedValues::Three));
+
+    assert_eq!("One", String::from(LimitedValues::One));
+    assert_eq!("Two", String::from(LimitedValues::Two));
+    assert_eq!("Three", String::from(LimitedValues::Three));
+
+    assert_eq!("One", LimitedValues::One.as_str());
+}
+
+#[test]
+fn test_custom_error() {
+    assert_eq!(Ok(CustomErrorValues::One), "One".try_into());
+    assert_eq!(
+        Err(UnknownValue("three".to_string())),
+        CustomErrorValues::try_from("three")
+    );
+    assert_eq!(
+        Err(UnknownValue("three".to_string())),
+        "three".parse::<CustomErrorValues>()
+    );
+
+    assert_eq!(Ok(CustomErrorFnValues::Two), "Two".parse());
+    assert_eq!(
+        Err(UnknownLength(5)),
+        CustomErrorFnValues::try_from("three".to_string())
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    let error: LimitedValuesParseError = LimitedValues::try_from("four").unwrap_err();
+    assert_eq!("four", error.value());
+    assert_eq!(&["One", "Two", "Three"], error.expected());
+    assert_eq!(
+        "unknown value \"four\", expected one of \"One\", \"Two\", \"Three\"",
+        error.to_string()
+    );
+
+    let boxed: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(
+        "unknown value \"four\", expected one of \"One\", \"Two\", \"Three\"",
+        boxed.to_string()
+    );
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(&[Values::One, Values::Two, Values::Three], Values::VARIANTS);
+    assert_eq!(
+        &[LimitedValues::One, LimitedValues::Two, LimitedValues::Three],
+        LimitedValues::VARIANTS
+    );
+}
+
+#[test]
+fn test_count() {
+    assert_eq!(3, Values::COUNT);
+    assert_eq!(3, LimitedValues::COUNT);
+    assert_eq!(2, AliasedValues::COUNT);
+    assert_eq!(Values::VARIANTS.len(), Values::COUNT);
+}
+
+#[test]
+fn test_iter() {
+    assert_eq!(
+        vec![Values::One, Values::Two, Values::Three],
+        Values::iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["first-value", "second-value", "THIRD"],
+        CasedValues::iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_ordinal() {
+    assert_eq!(Some(2), LimitedValues::Three.ordinal());
+    assert_eq!(Some(1), Values::Two.ordinal());
+    assert_eq!(None, Values::Other("Four".into()).ordinal());
+    assert_eq!(Some(0), LeadingFallbackValues::One.ordinal());
+    assert_eq!(None, SkippedValues::Pair(1, 2).ordinal());
+}
+
+#[test]
+fn test_from_ordinal() {
+    assert_eq!(Some(LimitedValues::Three), LimitedValues::from_ordinal(2));
+    assert_eq!(None, LimitedValues::from_ordinal(3));
+    assert_eq!(Some(Values::Two), Values::from_ordinal(1));
+    for value in Values::iter() {
+        assert_eq!(
+            Some(&value),
+            value.ordinal().and_then(Values::from_ordinal).as_ref()
+        );
+    }
+}
+
+#[test]
+fn test_is_known() {
+    assert!(Values::One.is_known());
+    assert!(!Values::One.is_other());
+    assert!(Values::from("Four").is_other());
+    assert!(!Values::from("Four").is_known());
+    assert!(HeaderValues::from("X-Custom").is_other());
+    assert!(!SkippedValues::Pair(1, 2).is_known());
+    assert!(!SkippedValues::Pair(1, 2).is_other());
+}
+
+#[test]
+fn test_known_or() {
+    assert_eq!(Values::Two, Values::Two.known_or(Values::One));
+    assert_eq!(Values::One, Values::from("Four").known_or(Values::One));
+    assert_eq!(
+        Values::Three,
+        Values::from("Four").known_or_else(|| Values::Three)
+    );
+    assert_eq!(
+        SkippedValues::Pair(1, 2),
+        SkippedValues::Pair(1, 2).known_or(SkippedValues::One)
+    );
+}
+
+#[test]
+fn test_to_known() {
+    assert_eq!(Some(MethodKnown::Post), Method::Post.to_known());
+    assert_eq!(None, Method::from("Put").to_known());
+    assert_eq!(None, Method::Internal(1).to_known());
+    let name = match Method::Get.to_known() {
+        Some(MethodKnown::Get) => "get",
+        Some(MethodKnown::Post) => "post",
+        None => "other",
+    };
+    assert_eq!("get", name);
+}
+
+#[test]
+fn test_known_enum() {
+    assert_eq!(Method::Get, Method::from(MethodKnown::Get));
+    assert_eq!(Ok(MethodKnown::Post), MethodKnown::try_from(Method::Post));
+    assert_eq!(
+        Err(Method::Other("Put".into())),
+        MethodKnown::try_from(Method::from("Put"))
+    );
+    assert_eq!(
+        Err(Method::Internal(1)),
+        MethodKnown::try_from(Method::Internal(1))
+    );
+}
+
+#[test]
+fn test_cow() {
+    assert_eq!(CowValues::One, "One".into());
+    assert_eq!(CowValues::Two, "Two".to_string().into());
+
+    match CowValues::from("Three") {
+        CowValues::Other(Cow::Borrowed(value)) => assert_eq!("Three", value),
+        value => panic!("expected a borrowed value, got {:?}", value),
+    }
+    match CowValues::from("Three".to_string()) {
+        CowValues::Other(Cow::Owned(value)) => assert_eq!("Three", value),
+        value => panic!("expected an owned value, got {:?}", value),
+    }
+    assert_eq!(Ok(CowValues::Other("Three".into())), "Three".parse());
+
+    assert_eq!("One", CowValues::One.to_string());
+    assert_eq!("Three", CowValues::from("Three").as_str());
+    assert_eq!("Three", String::from(CowValues::from("Three")));
+}
+
+#[test]
+fn test_borrowed() {
+    assert_eq!(BorrowedValues::One, "One".into());
+    assert_eq!(BorrowedValues::Two, "Two".into());
+
+    let input = "Three".to_string();
+    let value = BorrowedValues::from(input.as_str());
+    assert_eq!(BorrowedValues::Other("Three"), value);
+    assert_eq!("Three", value.as_str());
+    assert_eq!("Three", value.to_string());
+    assert_eq!("Three", String::from(value));
+    assert_eq!("One", String::from(BorrowedValues::One));
+}
+
+#[test]
+fn test_fallback_types() {
+    assert_eq!(BoxedValues::One, "One".into());
+    assert_eq!(BoxedValues::Other("Two".into()), "Two".into());
+    assert_eq!(BoxedValues::Other("Two".into()), "Two".to_string().into());
+    assert_eq!("Two", BoxedValues::from("Two").as_str());
+    assert_eq!("Two", BoxedValues::from("Two").to_string());
+    assert_eq!("Two", String::from(BoxedValues::from("Two")));
+
+    assert_eq!(SharedValues::One, "One".into());
+    assert_eq!(SharedValues::Other("Two".into()), "Two".parse().unwrap());
+    assert_eq!("Two", SharedValues::from("Two").as_str());
+    assert_eq!("Two", String::from(SharedValues::from("Two")));
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(DefaultValues::Two, DefaultValues::default());
+    assert_eq!(
+        DefaultFallbackValues::Other(String::new()),
+        DefaultFallbackValues::default()
+    );
+}
+
+#[test]
+fn test_skip() {
+    assert_eq!(SkippedValues::One, "One".into());
+    assert_eq!(SkippedValues::Other("Pair".to_string()), "Pair".into());
+    assert_eq!(&[SkippedValues::One], SkippedValues::VARIANTS);
+    assert_eq!("One", SkippedValues::One.as_str());
+
+    match SkippedValues::Pair(1, 2) {
+        SkippedValues::Pair(a, b) => assert_eq!(3, a + b),
+        value => panic!("expected a pair, got {:?}", value),
+    }
+}
+
+#[test]
+#[should_panic(expected = "SkippedValues::Pair has no string value")]
+fn test_skip_as_str() {
+    SkippedValues::Pair(1, 2).as_str();
+}
+
+#[test]
+fn test_generic() {
+    assert_eq!(GenericValues::<Box<str>>::One, "One".into());
+    assert_eq!(GenericValues::<Arc<str>>::Two, "Two".to_string().into());
+    assert_eq!(
+        GenericValues::<Box<str>>::Other("Three".into()),
+        "Three".into()
+    );
+    assert_eq!("Three", GenericValues::<Arc<str>>::from("Three").as_str());
+    assert_eq!("Two", GenericValues::<Arc<str>>::Two.to_string());
+    assert_eq!(
+        "Three",
+        String::from(GenericValues::<Box<str>>::from("Three"))
+    );
+    assert_eq!(
+        &[GenericValues::One, GenericValues::Two],
+        GenericValues::<Box<str>>::VARIANTS
+    );
+}
+
+#[test]
+fn test_display_catch_all() {
+    assert_eq!(TagValues::One, "One".into());
+    assert_eq!(TagValues::Other(Tag("two".to_string())), "Two".into());
+    assert_eq!(
+        TagValues::Other(Tag("three".to_string())),
+        "Three".to_string().into()
+    );
+    assert_eq!("#two", TagValues::from("Two").to_string());
+    assert_eq!("#two", String::from(TagValues::from("Two")));
+    assert_eq!("One", TagValues::One.to_string());
+
+    assert_eq!(DisplayValues::<String>::One, "One".into());
+    assert_eq!(DisplayValues::Other(2.5f64.to_string()), "2.5".into());
+    assert_eq!("2.5", DisplayValues::<String>::from("2.5").to_string());
+}
+
+#[test]
+fn test_display_with() {
+    assert_eq!(VersionValues::Latest, "Latest".into());
+    assert_eq!(VersionValues::Pinned(Version(1, 2)), "1.2".into());
+    assert_eq!("v1.2", VersionValues::from("1.2").to_string());
+    assert_eq!("v3.0", String::from(VersionValues::Pinned(Version(3, 0))));
+    assert_eq!("Latest", VersionValues::Latest.to_string());
+}
+
+#[test]
+fn test_catch_all_position() {
+    assert_eq!(LeadingFallbackValues::One, "One".into());
+    assert_eq!(LeadingFallbackValues::Two, "Two".parse().unwrap());
+    assert_eq!(
+        LeadingFallbackValues::Other("Three".to_string()),
+        "Three".into()
+    );
+    assert_eq!(
+        &[LeadingFallbackValues::One, LeadingFallbackValues::Two],
+        LeadingFallbackValues::VARIANTS
+    );
+}
+
+#[test]
+fn test_length_lookup() {
+    assert_eq!(BucketedValues::One, "One".into());
+    assert_eq!(BucketedValues::Two, "Two".to_string().into());
+    assert_eq!(BucketedValues::Three, "Three".into());
+    assert_eq!(BucketedValues::Three, "3".parse().unwrap());
+    assert_eq!(BucketedValues::Four, "Four".into());
+    assert_eq!(BucketedValues::Other("Six".to_string()), "Six".into());
+    assert_eq!(BucketedValues::Other("Eleven".to_string()), "Eleven".into());
+
+    assert_eq!(Ok(BucketedLimitedValues::Put), "PUT".parse());
+    assert_eq!(Ok(BucketedLimitedValues::Post), "post".parse());
+    assert!("Patch".parse::<BucketedLimitedValues>().is_err());
+}
+
+#[test]
+fn test_const_parse() {
+    const TWO: Option<LimitedValues> = LimitedValues::try_from_str("Two");
+    const FOUR: Option<LimitedValues> = LimitedValues::try_from_str("four");
+    assert_eq!(Some(LimitedValues::Two), TWO);
+    assert_eq!(None, FOUR);
+
+    assert_eq!(
+        Some(BucketedLimitedValues::Post),
+        BucketedLimitedValues::try_from_str("POST")
+    );
+}
+
+#[test]
+fn test_strnum_const() {
+    const THREE: LimitedValues = strnum_const!(LimitedValues, "Three");
+    assert_eq!(LimitedValues::Three, THREE);
+    assert_eq!(
+        BucketedLimitedValues::Get,
+        strnum_const!(BucketedLimitedValues, "GET")
+    );
+}
+
 #[test]
-fn test_values() {
-    assert_eq!(Values::One, "One".into());
-    assert_eq!(Values::Two, "Two".into());
-    assert_eq!(Values::Three, "Three".into());
-    assert_eq!(Values::Other("Four".to_string()), "Four".into());
+fn test_as_ref() {
+    fn length(value: impl AsRef<str>) -> usize {
+        value.as_ref().len()
+    }
 
-    assert_eq!("One", Values::to_string(&Values::One));
-    assert_eq!("Two", Values::to_string(&Values::Two));
-    assert_eq!("Three", Values::to_string(&Values::Three));
+    assert_eq!(3, length(Values::One));
+    assert_eq!(4, length(Values::from("Four")));
+    assert_eq!("Three", LimitedValues::Three.as_ref());
+}
+
+#[test]
+fn test_borrow() {
+    use std::borrow::Borrow;
+
+    let value = Values::from("Four");
+    let borrowed: &str = value.borrow();
+    assert_eq!("Four", borrowed);
+    assert_eq!("One", Borrow::<str>::borrow(&LimitedValues::One));
+}
+
+#[test]
+fn test_str_eq() {
+    assert!(ComparableValues::One == "One");
+    assert!(ComparableValues::One != "Two");
+    assert!(*"Two" == ComparableValues::Two);
+    assert!("Three" == ComparableValues::from("Three"));
+    assert!(ComparableValues::Two == String::from("Two"));
+    assert!(String::from("Four") == ComparableValues::from("Four"));
+}
+
+#[test]
+fn test_ord() {
+    let mut values = vec![
+        OrderedValues::Zulu,
+        OrderedValues::from("Mike"),
+        OrderedValues::Alpha,
+    ];
+    values.sort();
     assert_eq!(
-        "Four",
-        Values::to_string(&Values::Other("Four".to_string()))
+        vec![
+            OrderedValues::Alpha,
+            OrderedValues::Other("Mike".into()),
+            OrderedValues::Zulu
+        ],
+        values
     );
+    assert!(OrderedValues::Alpha < OrderedValues::Zulu);
+}
 
-    assert_eq!("One", String::from(Values::One));
-    assert_eq!("Two", String::from(Values::Two));
-    assert_eq!("Three", String::from(Values::Three));
-    assert_eq!("Four", String::from(Values::Other("Four".to_string())));
+#[test]
+fn test_hash() {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::new();
+    map.insert(HashedValues::One, 1);
+    map.insert(HashedValues::from("Three"), 3);
+    assert_eq!(Some(&1), map.get("One"));
+    assert_eq!(Some(&3), map.get("Three"));
+    assert_eq!(None, map.get("Two"));
+    assert_eq!(Some(&1), map.get(&HashedValues::One));
 }
 
 #[test]
-fn test_renamed() {
-    assert_eq!(RenamedValues::One, "one".into());
-    assert_eq!(RenamedValues::Two, "two".into());
-    assert_eq!(RenamedValues::Three, "three".into());
-    assert_eq!(RenamedValues::Other("four".to_string()), "four".into());
+fn test_static_str() {
+    const TWO: &str = LimitedValues::Two.into_static_str();
+    assert_eq!("Two", TWO);
+    let one: &'static str = LimitedValues::One.into();
+    assert_eq!("One", one);
+    assert_eq!("Two", <&str>::from(CustomErrorValues::Two));
+}
 
-    assert_eq!("one", RenamedValues::to_string(&RenamedValues::One));
-    assert_eq!("two", RenamedValues::to_string(&RenamedValues::Two));
-    assert_eq!("three", RenamedValues::to_string(&RenamedValues::Three));
+#[test]
+fn test_into_static_cow() {
+    use std::borrow::Cow;
+
+    assert!(matches!(Cow::from(Values::One), Cow::Borrowed("One")));
+    assert!(matches!(
+        Cow::from(LimitedValues::Two),
+        Cow::Borrowed("Two")
+    ));
+    let other: Cow<'static, str> = Values::from("Four").into();
+    assert!(matches!(&other, Cow::Owned(value) if value == "Four"));
+    let borrowed: Cow<'static, str> = BorrowedValues::from("Five").into();
+    assert_eq!("Five", borrowed);
+}
+
+#[test]
+fn test_prefix() {
+    assert_eq!(Ok(HeaderValues::Accept), "Accept".parse());
+    assert_eq!(Ok(HeaderValues::ContentType), "Content-Type".parse());
     assert_eq!(
-        "four",
-        RenamedValues::to_string(&RenamedValues::Other("four".to_string()))
+        Ok(HeaderValues::Extension("X-Request-Id".into())),
+        "X-Request-Id".parse()
+    );
+    assert_eq!(
+        Ok(HeaderValues::Extension("X-Trace".into())),
+        HeaderValues::try_from(String::from("X-Trace"))
+    );
+    let error = HeaderValues::try_from("Host").unwrap_err();
+    assert_eq!("Host", error.value());
+    assert_eq!(&["Accept", "Content-Type", "X-..."], error.expected());
+    assert_eq!(
+        "X-Request-Id",
+        HeaderValues::Extension("X-Request-Id".into()).as_str()
+    );
+    assert_eq!(
+        vec![HeaderValues::Accept, HeaderValues::ContentType],
+        HeaderValues::iter().collect::<Vec<_>>()
     );
+}
 
-    assert_eq!("one", String::from(RenamedValues::One));
-    assert_eq!("two", String::from(RenamedValues::Two));
-    assert_eq!("three", String::from(RenamedValues::Three));
+#[test]
+fn test_validate() {
+    assert_eq!(Ok(ValidatedValues::One), "One".parse());
     assert_eq!(
-        "four",
-        String::from(RenamedValues::Other("four".to_string()))
+        Ok(ValidatedValues::Other("some_name".into())),
+        ValidatedValues::try_from("some_name")
+    );
+    assert_eq!(
+        Ok(ValidatedValues::Other("other".into())),
+        ValidatedValues::try_from(String::from("other"))
+    );
+    let error = ValidatedValues::try_from("Not Valid").unwrap_err();
+    assert_eq!("Not Valid", error.value());
+    assert_eq!(&["One", "<is_identifier>"], error.expected());
+    assert!("a_much_too_long_identifier"
+        .parse::<ValidatedValues>()
+        .is_err());
+    assert_eq!(
+        "some_name",
+        ValidatedValues::Other("some_name".into()).as_str()
     );
 }
 
 #[test]
-fn test_limited() {
-    assert_eq!(Ok(LimitedValues::One), "One".try_into());
-    assert_eq!(Ok(LimitedValues::Two), "Two".try_into());
-    assert_eq!(Ok(LimitedValues::Three), "Three".try_into());
-    assert_eq!(Err("four".to_string()), LimitedValues::try_from("four"));
+fn test_default_on_error() {
+    assert_eq!(Transport::Tcp, "tcp".into());
+    assert_eq!(Transport::Udp, String::from("udp").into());
+    assert_eq!(Transport::Unknown, "sctp".into());
+    assert_eq!(Transport::Unknown, "unknown".into());
+    assert_eq!(Ok(Transport::Unknown), "quic".parse());
+    assert_eq!("unknown", Transport::from("sctp").as_str());
+    assert_eq!(Ok(Transport::Unknown), Transport::try_from(&b"\xff"[..0]));
+    assert!(Transport::try_from(&b"\xff"[..]).is_err());
+    assert!(Transport::try_from_known("sctp").is_err());
+    assert_eq!(Ok(Transport::Tcp), Transport::try_from_known("tcp"));
+    assert_eq!(
+        &[Transport::Tcp, Transport::Udp, Transport::Unknown],
+        Transport::VARIANTS
+    );
+}
 
-    assert_eq!("One", LimitedValues::to_string(&LimitedValues::One));
-    assert_eq!("Two", LimitedValues::to_string(&LimitedValues::Two));
-    assert_eq!("Three", LimitedValues::to_string(&LimitedValues::Three));
+#[test]
+fn test_skip_impls() {
+    assert_eq!("<One>", HandWrittenValues::One.to_string());
+    assert_eq!("<Two>", String::from(HandWrittenValues::Two));
+    assert_eq!(HandWrittenValues::Two, "<Two>".into());
+    assert_eq!(HandWrittenValues::Other("Three".into()), "<Three>".into());
+    assert_eq!(
+        Some(HandWrittenValues::One),
+        HandWrittenValues::parse_opt("<One>")
+    );
+    assert_eq!(
+        Ok(HandWrittenValues::One),
+        HandWrittenValues::try_from(&b"One"[..])
+    );
+    assert_eq!("One", HandWrittenValues::One.as_str());
+}
 
-    assert_eq!("One", String::from(LimitedValues::One));
-    assert_eq!("Two", String::from(LimitedValues::Two));
-    assert_eq!("Three", String::from(LimitedValues::Three));
+#[test]
+fn test_display_padding() {
+    assert_eq!("  One", format!("{:>5}", Values::One));
+    assert_eq!("Two--", format!("{:-<5}", Values::Two));
+    assert_eq!("Fo", format!("{:.2}", Values::Other("Four".into())));
+    assert_eq!(" 404 ", format!("{:^5}", StatusValues::NotFound));
+    assert_eq!("  500", format!("{:>5}", StatusValues::Code(500)));
+    assert_eq!("500", format!("{}", StatusValues::Code(500)));
+}
+
+#[test]
+fn test_round_trip() {
+    // values stored in the fallback option convert back into the input
+    for input in &["Three", "three", " Three "] {
+        assert_eq!(*input, String::from(Values::from(*input)));
+        assert_eq!(*input, String::from(Values::from(input.to_string())));
+    }
+    for input in &["name2", "NAME2", "X-Custom-Foo", "x-FOO"] {
+        assert_eq!(*input, String::from(KeyValues::from(*input)));
+    }
+    // trim, percent_decode and normalize change the stored value by default
+    assert_eq!("Zürich", String::from(EncodedValues::from("Z%C3%BCrich")));
+    assert_eq!("blue", String::from(Color::from("\u{feff}Blue")));
+
+    for input in &[" Blue ", "Blue%20Sky", "\u{feff}BLUE", "X-Foo", " x-%41 "] {
+        assert_eq!(*input, String::from(OriginalColor::from(*input)));
+        assert_eq!(*input, String::from(OriginalColor::from(input.to_string())));
+        assert_eq!(*input, input.parse::<OriginalColor>().unwrap().to_string());
+    }
+    assert_eq!(OriginalColor::Gray, " grey ".into());
+    assert_eq!(OriginalColor::Red, String::from("%52ed").into());
+    assert_eq!(OriginalColor::Custom(" x-%41 ".into()), " x-%41 ".into());
+    assert_eq!(
+        OriginalColor::Other("Blue%20Sky".into()),
+        "Blue%20Sky".into()
+    );
+    assert_eq!(Ok(OriginalColor::Other(" Blue ".into())), " Blue ".parse());
+}
+
+#[test]
+fn test_deprecated() {
+    assert_eq!(DeprecatedRegion::EuWest, "europe".into());
+    assert_eq!(DeprecatedRegion::UsEast, "us".into());
+    assert!(!DeprecatedRegion::EuWest.is_deprecated());
+    assert!(DeprecatedRegion::UsEast.is_deprecated());
+    assert!(!DeprecatedRegion::Other("ap-south".into()).is_deprecated());
+
+    assert!(DeprecatedRegion::is_deprecated_str("Europe"));
+    assert!(DeprecatedRegion::is_deprecated_str("UsEast"));
+    assert!(DeprecatedRegion::is_deprecated_str("US"));
+    assert!(!DeprecatedRegion::is_deprecated_str("eu-west"));
+    assert!(!DeprecatedRegion::is_deprecated_str("ap-south"));
+}
+
+#[test]
+fn test_props() {
+    let code: u16 = HttpError::NotFound.code();
+    assert_eq!(404, code);
+    assert_eq!(503, HttpError::from("unavailable").code());
+    assert_eq!(500, HttpError::Internal(3).code());
+    assert!(!HttpError::NotFound.retryable());
+    assert!(HttpError::Unavailable.retryable());
+    assert!(HttpError::from("timeout").retryable());
+    assert_eq!("Not Found", HttpError::NotFound.reason());
+    assert_eq!("", HttpError::Other("timeout".into()).reason());
+}
+
+#[test]
+fn test_category() {
+    assert_eq!(&["verbose", "problem"], CategorizedLogLevel::CATEGORIES);
+    assert_eq!("verbose", CategorizedLogLevel::Debug.category());
+    assert_eq!("problem", CategorizedLogLevel::from("warn").category());
+    assert_eq!("", CategorizedLogLevel::Info.category());
+    assert_eq!("", CategorizedLogLevel::from("fatal").category());
+    assert_eq!(
+        vec![CategorizedLogLevel::Warn, CategorizedLogLevel::Error],
+        CategorizedLogLevel::iter_category("problem").collect::<Vec<_>>()
+    );
+    assert_eq!(0, CategorizedLogLevel::iter_category("other").count());
+}
+
+#[test]
+fn test_newtype() {
+    assert_eq!(Username("alice".into()), " alice ".into());
+    assert_eq!(Username("bob".into()), String::from("bob").into());
+    assert_eq!(Ok(Username("carol".into())), "carol".parse());
+    assert_eq!("alice", String::from(Username::from("alice")));
+    assert_eq!("[  alice]", format!("[{:>7}]", Username::from("alice")));
+
+    assert_eq!("app_mail", AppName::try_from("app_mail").unwrap().as_str());
+    assert!(AppName::is_valid("app_mail"));
+    assert!(!AppName::is_valid("mail"));
+    assert!(!AppName::is_valid("app_Mail"));
+    let error = AppName::try_from(String::from("app-mail")).unwrap_err();
+    assert_eq!("app-mail", error.value());
+    assert_eq!("invalid value \"app-mail\" for AppName", error.to_string());
+    assert_eq!(
+        "app_mail",
+        String::from(AppName::try_from("app_mail").unwrap())
+    );
+
+    assert_eq!(Ok(Language("EN".into())), "EN".parse());
+    assert_eq!(Ok(Language("de".into())), Language::try_from("de"));
+    assert!(Language::try_from("fr").is_err());
+    assert_eq!("de", Language::try_from("de").unwrap().into_inner());
+}
+
+roundtrip_tests!(AliasedValues);
+roundtrip_tests!(InsensitiveValues);
+roundtrip_tests!(DeprecatedRegion);
+
+#[test]
+fn test_try_from_known() {
+    assert_eq!(Ok(Values::One), Values::try_from_known("One"));
+    let error = Values::try_from_known("Four").unwrap_err();
+    assert_eq!("Four", error.value());
+    assert_eq!(&["One", "Two", "Three"], error.expected());
+    assert_eq!(
+        Ok(KeyValues::Extension("x-trace".into())),
+        KeyValues::try_from_known("x-trace")
+    );
+    assert!(KeyValues::try_from_known("host").is_err());
+}
+
+#[test]
+fn test_parse_with() {
+    assert_eq!(Priority::Low, "Low".into());
+    assert_eq!(Priority::High, "High".into());
+    assert_eq!(Priority::Low, "3".into());
+    assert_eq!(Priority::High, "7".to_string().into());
+    assert_eq!(Priority::Other("12".into()), "12".into());
+    assert_eq!("Low", Priority::from("3").as_str());
+
+    assert_eq!(Ok(StrictPriority::High), "URGENT".parse());
+    assert_eq!(Ok(StrictPriority::High), StrictPriority::try_from("High"));
+    assert!(StrictPriority::try_from("normal").is_err());
+}
+
+#[test]
+fn test_multiple_prefixes() {
+    assert_eq!(KeyValues::Name, "NAME".into());
+    assert_eq!(
+        KeyValues::Custom("X-Custom-Color".into()),
+        "X-Custom-Color".into()
+    );
+    assert_eq!(KeyValues::Extension("x-color".into()), "x-color".into());
+    assert_eq!(KeyValues::Other("color".into()), "color".into());
+    assert_eq!(
+        KeyValues::Extension("x-size".into()),
+        String::from("x-size").into()
+    );
+    assert_eq!("x-color", KeyValues::from("x-color").as_str());
+}
+
+#[test]
+fn test_parse_number() {
+    assert_eq!(Ok(StatusValues::Ok), "200".parse());
+    assert_eq!(Ok(StatusValues::Code(418)), "418".parse());
+    assert_eq!(
+        Ok(StatusValues::Code(503)),
+        StatusValues::try_from(String::from("503"))
+    );
+    assert!(StatusValues::try_from("teapot").is_err());
+    assert!(StatusValues::try_from("70000").is_err());
+    assert_eq!("418", StatusValues::Code(418).to_string());
+    assert_eq!("418", String::from(StatusValues::Code(418)));
+    assert_eq!("404", Cow::from(StatusValues::NotFound));
+}
+
+#[test]
+#[should_panic]
+fn test_parse_number_as_str() {
+    StatusValues::Code(418).as_str();
+}
+
+#[test]
+fn test_trim() {
+    assert_eq!(TrimmedValues::One, " One\n".into());
+    assert_eq!(TrimmedValues::Two, String::from("\tTwo ").into());
+    assert_eq!(TrimmedValues::Other("Three".into()), "  Three ".into());
+    assert_eq!(
+        TrimmedValues::Other("Four".into()),
+        String::from("Four ").into()
+    );
+    assert_eq!(Ok(TrimmedValues::One), " One".parse());
+    assert_eq!(Ok(TrimmedLimitedValues::Two), " Two ".parse());
+    assert_eq!(
+        Ok(TrimmedLimitedValues::One),
+        TrimmedLimitedValues::try_from(String::from("One "))
+    );
+    assert_eq!(
+        "Three",
+        TrimmedLimitedValues::try_from(" Three ")
+            .unwrap_err()
+            .value()
+    );
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(EmptyValues::Empty, "".into());
+    assert_eq!(EmptyValues::Other(" ".into()), " ".into());
+    assert_eq!("", EmptyValues::Empty.as_str());
+
+    assert_eq!(Ok(UnsetValues::Unset), "".parse());
+    assert_eq!(Ok(UnsetValues::Unset), "  ".parse());
+    assert_eq!(Ok(UnsetValues::Unset), "Unset".parse());
+    assert_eq!("Unset", UnsetValues::Unset.as_str());
+}
+
+#[test]
+fn test_parse_opt() {
+    assert_eq!(None, Values::parse_opt(""));
+    assert_eq!(None, Values::parse_opt(" \t"));
+    assert_eq!(Some(Values::One), Values::parse_opt("One"));
+    assert_eq!(
+        Some(Values::Other("Four".into())),
+        Values::parse_opt("Four")
+    );
+    assert_eq!(
+        Some(BorrowedValues::Other("Four")),
+        BorrowedValues::parse_opt("Four")
+    );
+
+    assert_eq!(Ok(None), LimitedValues::parse_opt(" "));
+    assert_eq!(
+        Ok(Some(LimitedValues::Two)),
+        LimitedValues::parse_opt("Two")
+    );
+    assert!(LimitedValues::parse_opt("Four").is_err());
+}
+
+#[test]
+fn test_canonical_case() {
+    let value = InsensitiveValues::from("POST");
+    assert_eq!(InsensitiveValues::Post, value);
+    assert_eq!("Post", value.to_string());
+    assert_eq!("Delete", String::from(InsensitiveValues::from("dEl")));
+
+    assert_eq!(PreservedValues::Amsterdam, "Amsterdam".into());
+    let value = PreservedValues::from("AMSTERDAM");
+    assert_eq!(PreservedValues::Other("AMSTERDAM".into()), value);
+    assert_eq!("AMSTERDAM", value.to_string());
+}
+
+#[test]
+fn test_named_field() {
+    assert_eq!(NamedFieldValues::One, "One".into());
+    assert_eq!(
+        NamedFieldValues::Other {
+            value: "Three".into()
+        },
+        "Three".into()
+    );
+    let value = NamedFieldValues::from(String::from("Four"));
+    assert_eq!("Four", value.as_str());
+    assert_eq!("Four", value.to_string());
+    assert_eq!("Four", String::from(value));
+}
+
+#[test]
+fn test_nested() {
+    assert_eq!(Zone::Global, "Global".into());
+    assert_eq!(Zone::Europe(Region::West), "eu.west".into());
+    assert_eq!(Zone::America(Region::North), "us-north".into());
+    assert_eq!(Zone::Other("eu.south".into()), "eu.south".into());
+    assert_eq!(
+        Zone::Other("eu-west".into()),
+        String::from("eu-west").into()
+    );
+    assert_eq!("eu.west", Zone::Europe(Region::West).to_string());
+    assert_eq!("us-north", String::from(Zone::America(Region::North)));
+}
+
+#[test]
+fn test_repr() {
+    assert_eq!(Ok(Protocol::Udp), "udp".try_into());
+    assert_eq!(Ok(Protocol::Tcp), Protocol::try_from(6u16));
+    assert_eq!(Ok(Protocol::Reserved), Protocol::try_from(255u16));
+    assert_eq!(Err(1), Protocol::try_from(1u16));
+    assert_eq!(17u16, u16::from(Protocol::Udp));
+    assert_eq!(255u16, Protocol::Reserved.into());
+}
+
+#[test]
+fn test_set() {
+    let mut set = FeatureSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(Feature::Three));
+    assert!(set.insert(Feature::One));
+    assert!(!set.insert(Feature::One));
+    assert_eq!(2, set.len());
+    assert!(set.contains(&Feature::One));
+    assert!(!set.contains(&Feature::Two));
+    assert_eq!(
+        vec![Feature::One, Feature::Three],
+        set.iter().collect::<Vec<_>>()
+    );
+    assert_eq!("One,Three", set.to_string());
+    assert_eq!(Ok(set), "Three,One".parse());
+    assert_eq!(Ok(FeatureSet::new()), "".parse());
+    assert!("One,Four".parse::<FeatureSet>().is_err());
+    assert_eq!(3, FeatureSet::all().len());
+    assert!(set.remove(Feature::One));
+    assert_eq!(
+        set,
+        vec![Feature::Three].into_iter().collect::<FeatureSet>()
+    );
+}
+
+#[test]
+fn test_description() {
+    assert_eq!("The first value", DescribedValues::One.description());
+    assert_eq!("Value number two", DescribedValues::Two.description());
+    assert_eq!("The third value", DescribedValues::Three.description());
+    assert_eq!("", DescribedValues::Four.description());
+    assert_eq!("", DescribedValues::from("Five").description());
+}
+
+#[test]
+fn test_locale() {
+    assert_eq!("Montag", Weekday::Mon.to_string_locale("de"));
+    assert_eq!("Tuesday", Weekday::Tue.to_string_locale("en"));
+    assert_eq!("Tue", Weekday::Tue.to_string_locale("de"));
+    assert_eq!(Some(Weekday::Mon), Weekday::from_str_locale("Montag", "de"));
+    assert_eq!(None, Weekday::from_str_locale("Montag", "en"));
+    assert_eq!(Ok(Weekday::Mon), "Mon".parse());
+}
+
+#[test]
+fn test_short_long() {
+    assert_eq!(State::NewYork, "NY".into());
+    assert_eq!(State::NewYork, "New York".into());
+    assert_eq!(State::California, "CA".into());
+    assert_eq!("NY", State::NewYork.as_short());
+    assert_eq!("New York", State::NewYork.as_long());
+    assert_eq!("New York", State::NewYork.to_string());
+    assert_eq!("Texas", State::Texas.as_short());
+    assert_eq!("Ohio", State::from("Ohio").as_short());
+}
+
+#[test]
+fn test_abbrev() {
+    assert_eq!(Ok(Command::Add), Command::from_abbrev("a"));
+    assert_eq!(Ok(Command::Remove), Command::from_abbrev("remo"));
+    assert_eq!(Ok(Command::Remove), Command::from_abbrev("rm"));
+    assert_eq!(Ok(Command::Rename), Command::from_abbrev("REN"));
+    assert_eq!(Ok(Command::Get), Command::from_abbrev("get"));
+    assert_eq!(Ok(Command::GetAll), Command::from_abbrev("geta"));
+    assert_eq!(
+        Err(CommandAbbrevError::Ambiguous),
+        Command::from_abbrev("re")
+    );
+    assert_eq!(Err(CommandAbbrevError::Unknown), Command::from_abbrev("x"));
+    assert_eq!(Err(CommandAbbrevError::Unknown), Command::from_abbrev(""));
+}
+
+#[test]
+fn test_from_env() {
+    std::env::set_var("STRNUM_TEST_LOG_LEVEL", "info");
+    assert_eq!(
+        Ok(LogLevel::Info),
+        LogLevel::from_env("STRNUM_TEST_LOG_LEVEL")
+    );
+    std::env::set_var("STRNUM_TEST_LOG_LEVEL_INVALID", "verbose");
+    let error = LogLevel::from_env("STRNUM_TEST_LOG_LEVEL_INVALID").unwrap_err();
+    assert_eq!("STRNUM_TEST_LOG_LEVEL_INVALID", error.variable());
+    assert_eq!(
+        "invalid value \"verbose\" for environment variable \"STRNUM_TEST_LOG_LEVEL_INVALID\", expected one of \"debug\", \"info\", \"warn\"",
+        error.to_string()
+    );
+    assert_eq!(
+        Err(LogLevelEnvError::Missing {
+            variable: "STRNUM_TEST_LOG_LEVEL_MISSING".into()
+        }),
+        LogLevel::from_env("STRNUM_TEST_LOG_LEVEL_MISSING")
+    );
+}
+
+#[test]
+fn test_percent_decode() {
+    assert_eq!(EncodedValues::NewYork, "New%20York".into());
+    assert_eq!(EncodedValues::NewYork, String::from("New%20York").into());
+    assert_eq!(EncodedValues::NewYork, "New York".into());
+    assert_eq!(EncodedValues::Other("Zürich".into()), "Z%C3%BCrich".into());
+    assert_eq!(EncodedValues::Other("100%".into()), "100%".into());
+    assert_eq!(EncodedValues::Other("%zz".into()), "%zz".into());
+    assert_eq!(Ok(EncodedLimitedValues::Path), "a%2Fb%20".parse());
+    assert!(EncodedLimitedValues::try_from("a%2Fc").is_err());
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(Color::Red, "red".into());
+    assert_eq!(Color::Red, "RED".into());
+    assert_eq!(Color::Gray, "grey".to_string().into());
+    assert_eq!(Color::Gray, "\u{feff}Gray".into());
+    assert_eq!(Color::Other("blue".into()), "Blue".into());
+    assert_eq!(
+        Color::Other("blue".into()),
+        "\u{feff}blue".to_string().into()
+    );
+    assert_eq!(Color::Other("green".into()), "green".to_string().into());
+    assert_eq!(Ok(LimitedColor::Gray), "GREY".parse());
+    assert_eq!(
+        Ok(LimitedColor::Red),
+        LimitedColor::try_from(String::from("Red"))
+    );
+    assert!(LimitedColor::try_from("blue").is_err());
+}
+
+#[test]
+fn test_ffi() {
+    use std::ffi::{CStr, CString};
+
+    let two = CStr::from_bytes_with_nul(b"Two\0").unwrap();
+    assert_eq!(Ok(FfiLimitedValues::Two), FfiLimitedValues::try_from(two));
+    assert_eq!(FfiValues::Two, FfiValues::from(two));
+    let three = CStr::from_bytes_with_nul(b"Three\0").unwrap();
+    assert!(FfiLimitedValues::try_from(three).is_err());
+    assert_eq!(FfiValues::Other("Three".into()), FfiValues::from(three));
+
+    assert_eq!(
+        CString::new("One").unwrap(),
+        FfiLimitedValues::One.to_c_string()
+    );
+    assert_eq!(
+        Ok(CString::new("Three").unwrap()),
+        FfiValues::from("Three").to_c_string()
+    );
+    assert!(FfiValues::from("Th\0ree").to_c_string().is_err());
+}
+
+#[test]
+fn test_bytes() {
+    assert_eq!(b"Two", Values::Two.as_bytes());
+    assert_eq!(Ok(Values::Two), Values::try_from(&b"Two"[..]));
+    assert_eq!(
+        Ok(Values::Other("Four".into())),
+        Values::try_from(&b"Four"[..])
+    );
+    assert!(Values::try_from(&b"\xff"[..]).is_err());
+    assert_eq!(Ok(LimitedValues::One), LimitedValues::try_from(&b"One"[..]));
+    let error = LimitedValues::try_from(&b"F\xffur"[..]).unwrap_err();
+    assert_eq!("F\u{fffd}ur", error.value());
+    match BorrowedValues::try_from(&b"Three"[..]) {
+        Ok(BorrowedValues::Other(value)) => assert_eq!("Three", value),
+        value => panic!("expected a borrowed value, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_from_utf8_lossy() {
+    assert_eq!(Values::One, Values::from_utf8_lossy(b"One"));
+    assert_eq!(
+        Values::Other("F\u{fffd}ur".into()),
+        Values::from_utf8_lossy(b"F\xffur")
+    );
+    match CowValues::from_utf8_lossy(b"Three") {
+        CowValues::Other(Cow::Borrowed(value)) => assert_eq!("Three", value),
+        value => panic!("expected a borrowed value, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(
+        "DebugValues(\"New York\")",
+        format!("{:?}", DebugValues::NewYork)
+    );
+    assert_eq!(
+        "DebugValues(\"say \\\"hi\\\"\")",
+        format!("{:?}", DebugValues::from("say \"hi\""))
+    );
+    assert_eq!(
+        "DebugValues(\"12\")",
+        format!("{:?}", DebugValues::Number(12))
+    );
+    assert_eq!(
+        "DebugValues(\"quoted\\\"Two\")",
+        format!("{:?}", DebugValues::Quoted(LimitedValues::Two))
+    );
+    assert_eq!(
+        "DebugValues::Internal",
+        format!("{:?}", DebugValues::Internal(1))
+    );
+}
+
+mod shadowed_prelude {
+    #![allow(dead_code)]
+
+    use strnum::StrNum;
+
+    type Result<T> = std::result::Result<T, ()>;
+    type Option = ();
+    struct Some;
+    struct Ok;
+    struct Err;
+
+    #[derive(StrNum, Debug, PartialEq)]
+    #[strnum(trim)]
+    pub enum Values {
+        One,
+        Two,
+        Other(String),
+    }
+
+    #[derive(StrNum, Debug, PartialEq)]
+    pub enum LimitedValues {
+        One,
+        #[value(prefix = "x-")]
+        Extension(String),
+    }
+}
+
+#[test]
+fn test_shadowed_prelude() {
+    use shadowed_prelude::{LimitedValues, Values};
+
+    assert_eq!(Values::One, " One ".into());
+    assert_eq!(Values::Other("Three".into()), "Three".into());
+    assert_eq!(Some(Values::Two), Values::parse_opt("Two"));
+    assert_eq!(Ok(LimitedValues::One), "One".parse());
+    assert_eq!(
+        Ok(LimitedValues::Extension("x-one".into())),
+        LimitedValues::try_from("x-one")
+    );
+    assert!(LimitedValues::try_from("Two").is_err());
 }
@@ -0,0 +1,39 @@
+#![cfg(feature = "utoipa")]
+
+use serde_json::json;
+use strnum::StrNum;
+use utoipa::ToSchema;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    #[value = "two"]
+    Two,
+}
+
+#[test]
+fn test_schema() {
+    let (name, schema) = LimitedValues::schema();
+    assert_eq!("LimitedValues", name);
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json!("string"), schema["type"]);
+    assert_eq!(json!(["One", "two"]), schema["enum"]);
+
+    let (name, schema) = Values::schema();
+    assert_eq!("Values", name);
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json!("string"), schema["type"]);
+    assert!(schema.get("enum").is_none());
+    assert_eq!(
+        json!("Known values: \"One\", \"two\", other values are accepted too"),
+        schema["description"]
+    );
+}
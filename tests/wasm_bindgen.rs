@@ -0,0 +1,43 @@
+#![cfg(all(feature = "wasm-bindgen", target_arch = "wasm32"))]
+
+use std::convert::TryFrom;
+use strnum::StrNum;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(StrNum, Debug, PartialEq)]
+enum Values {
+    One,
+    #[value = "two"]
+    Two,
+    Other(String),
+}
+
+#[derive(StrNum, Debug, PartialEq)]
+enum LimitedValues {
+    One,
+    Two,
+}
+
+#[wasm_bindgen_test]
+fn test_round_trip() {
+    let value = JsValue::from(Values::Two);
+    assert_eq!(Some("two".to_string()), value.as_string());
+    assert_eq!(Values::Two, Values::from(value));
+    assert_eq!(
+        Values::Other("Three".to_string()),
+        Values::from(JsValue::from_str("Three"))
+    );
+    assert_eq!(
+        Values::Other("true".to_string()),
+        Values::from(JsValue::from_bool(true))
+    );
+
+    assert_eq!(
+        Ok(LimitedValues::Two),
+        LimitedValues::try_from(JsValue::from_str("Two"))
+    );
+    assert!(LimitedValues::try_from(JsValue::from_str("Three")).is_err());
+    let number = JsValue::from_f64(1.0);
+    assert_eq!(Err(number.clone()), LimitedValues::try_from(number));
+}